@@ -1,32 +1,51 @@
-use error::{Error, RequestError};
+use error::{Error, OAuthError, RequestError};
 use reqwest;
-use reqwest::Url;
+use reqwest::{Url, UrlError};
 use reqwest::Method;
 use reqwest::header::Headers;
 use serde;
+use serde_qs;
 use serde::de::DeserializeOwned;
+use std::io::Write;
 
 const DEFAULT_API_URL: &'static str = "https://api.stripe.com/v1";
+const DEFAULT_CONNECT_URL: &'static str = "https://connect.stripe.com";
+const DEFAULT_UPLOADS_URL: &'static str = "https://files.stripe.com/v1";
 
 #[derive(Clone)]
 pub struct Client {
     inner: reqwest::Client,
     api_url: String,
+    connect_url: String,
+    uploads_url: String,
     secret_key: String,
     stripe_account_id: Option<String>,
+    idempotency_key: Option<String>,
 }
 
 impl Client {
+    // NOTE: the base urls are validated when set, so joining a path to them can't fail
     fn url(&self, path: &str) -> Url {
         Url::parse(&format!("{}/{}", self.api_url, &path[1..])).unwrap()
     }
 
+    pub(crate) fn connect_url(&self, path: &str) -> Url {
+        Url::parse(&format!("{}/{}", self.connect_url, &path[1..])).unwrap()
+    }
+
+    fn uploads_url(&self, path: &str) -> Url {
+        Url::parse(&format!("{}/{}", self.uploads_url, &path[1..])).unwrap()
+    }
+
     pub fn new<Str: Into<String>>(secret_key: Str) -> Client {
         Client {
             inner: reqwest::Client::new(),
             api_url: DEFAULT_API_URL.to_owned(),
+            connect_url: DEFAULT_CONNECT_URL.to_owned(),
+            uploads_url: DEFAULT_UPLOADS_URL.to_owned(),
             secret_key: secret_key.into(),
             stripe_account_id: None,
+            idempotency_key: None,
         }
     }
 
@@ -48,8 +67,58 @@ impl Client {
         self.stripe_account_id = Some(account_id.into());
     }
 
+    /// Clones a new client which sends the given Idempotency-Key header.
+    ///
+    /// The key is sent with every request made by the returned client, so it should
+    /// only be used for a single request (or the retries of that request).
+    ///
+    /// For more details see https://stripe.com/docs/api#idempotent_requests.
+    pub fn with_idempotency_key<Str: Into<String>>(&self, key: Str) -> Client {
+        let mut client = self.clone();
+        client.idempotency_key = Some(key.into());
+        client
+    }
+
+    /// Sets the base url of the Stripe API (eg. to use a mock server in tests).
+    ///
+    /// Defaults to "https://api.stripe.com/v1", and fails if the url can't be parsed.
+    pub fn set_api_url<Str: Into<String>>(&mut self, api_url: Str) -> Result<(), Error> {
+        self.api_url = parse_base_url(api_url.into())?;
+        Ok(())
+    }
+
+    /// Sets the base url used for Stripe Connect OAuth requests.
+    ///
+    /// Defaults to "https://connect.stripe.com", and fails if the url can't be parsed.
+    pub fn set_connect_url<Str: Into<String>>(&mut self, connect_url: Str) -> Result<(), Error> {
+        self.connect_url = parse_base_url(connect_url.into())?;
+        Ok(())
+    }
+
+    /// Sets the base url used for uploading files.
+    ///
+    /// Defaults to "https://files.stripe.com/v1", and fails if the url can't be parsed.
+    pub fn set_uploads_url<Str: Into<String>>(&mut self, uploads_url: Str) -> Result<(), Error> {
+        self.uploads_url = parse_base_url(uploads_url.into())?;
+        Ok(())
+    }
+
     fn request(&self, method: Method, path: &str) -> reqwest::RequestBuilder {
         let url = self.url(path);
+        self.request_url(method, url)
+    }
+
+    /// Builds the url of a request, with its params encoded in the query string.
+    fn url_with_params<Q: serde::Serialize>(&self, path: &str, query_params: Q) -> Result<Url, Error> {
+        let mut url = self.url(path);
+        let query = serde_qs::to_string(&query_params)?;
+        if !query.is_empty() {
+            url.set_query(Some(&query));
+        }
+        Ok(url)
+    }
+
+    fn request_url(&self, method: Method, url: Url) -> reqwest::RequestBuilder {
         let mut request = self.inner.request(method, url);
         request.headers(self.headers());
         request
@@ -65,8 +134,8 @@ impl Client {
         path: &str,
         query_params: Q,
     ) -> Result<T, Error> {
-        let mut request = self.request(Method::Get, path);
-        request.query(&query_params);
+        let url = self.url_with_params(path, query_params)?;
+        let mut request = self.request_url(Method::Get, url);
         process_response(request.send()?)
     }
 
@@ -84,7 +153,7 @@ impl Client {
         body_params: B,
     ) -> Result<T, Error> {
         let mut request = self.request(Method::Post, path);
-        request.form(&body_params);
+        request.body(serde_qs::to_string(&body_params)?);
         process_response(request.send()?)
     }
 
@@ -98,12 +167,46 @@ impl Client {
         path: &str,
         query_params: Q,
     ) -> Result<T, Error> {
-        let mut request = self.request(Method::Delete, path);
-        request.query(&query_params);
+        let url = self.url_with_params(path, query_params)?;
+        let mut request = self.request_url(Method::Delete, url);
         process_response(request.send()?)
     }
 
-    fn headers(&self) -> Headers {
+    /// Sends a multipart/form-data request to the uploads host.
+    pub fn upload<T: DeserializeOwned>(&self, path: &str, form: reqwest::multipart::Form) -> Result<T, Error> {
+        let url = self.uploads_url(path);
+        let mut request = self.request_url(Method::Post, url);
+        request.multipart(form); // NOTE: replaces the url-encoded content type set by `headers`
+        process_response(request.send()?)
+    }
+
+    /// Downloads the contents of a file from the uploads host, streaming them into `writer`.
+    ///
+    /// Returns the number of bytes written.
+    pub fn download<W: Write + ?Sized>(&self, path: &str, writer: &mut W) -> Result<u64, Error> {
+        let url = self.uploads_url(path);
+        let mut request = self.request_url(Method::Get, url);
+        let mut response = request.send()?;
+        if response.status().as_u16() != 200 {
+            return process_response(response);
+        }
+        Ok(response.copy_to(writer)?)
+    }
+
+    pub fn connect_post_with_params<T: DeserializeOwned, B: serde::Serialize>(
+        &self,
+        path: &str,
+        body_params: B,
+    ) -> Result<T, Error> {
+        // NOTE: the per-account headers must not be sent to connect.stripe.com
+        let url = self.connect_url(path);
+        let mut request = self.inner.request(Method::Post, url);
+        request.headers(self.auth_headers());
+        request.body(serde_qs::to_string(&body_params)?);
+        process_oauth_response(request.send()?)
+    }
+
+    fn auth_headers(&self) -> Headers {
         use reqwest::header::{Authorization, Basic, ContentType};
 
         let mut headers = Headers::new();
@@ -112,13 +215,31 @@ impl Client {
             password: None,
         }));
         headers.set(ContentType::form_url_encoded());
+        headers
+    }
+
+    fn headers(&self) -> Headers {
+        let mut headers = self.auth_headers();
         if let Some(ref account) = self.stripe_account_id {
             headers.set_raw("Stripe-Account", vec![account.as_bytes().to_vec()]);
         }
+        if let Some(ref key) = self.idempotency_key {
+            headers.set_raw("Idempotency-Key", vec![key.as_bytes().to_vec()]);
+        }
         headers
     }
 }
 
+fn parse_base_url(url: String) -> Result<String, Error> {
+    match Url::parse(&url) {
+        Ok(ref parsed) if parsed.cannot_be_a_base() => {
+            Err(Error::Conversion(Box::new(UrlError::RelativeUrlWithCannotBeABaseBase)))
+        }
+        Ok(_) => Ok(url),
+        Err(err) => Err(Error::Conversion(Box::new(err))),
+    }
+}
+
 fn process_response<T: DeserializeOwned>(mut response: reqwest::Response) -> Result<T, Error> {
     #[derive(Debug, Deserialize)]
     struct ErrorWrapper {
@@ -137,3 +258,18 @@ fn process_response<T: DeserializeOwned>(mut response: reqwest::Response) -> Res
         }
     }
 }
+
+fn process_oauth_response<T: DeserializeOwned>(mut response: reqwest::Response) -> Result<T, Error> {
+    match response.status().as_u16() {
+        200 => response.json().map_err(|err| Error::from(err)),
+        _ => {
+            Err(match response.json() {
+                Ok(oauth_err_object) => {
+                    let oauth_err_object: OAuthError = oauth_err_object;
+                    Error::from(oauth_err_object)
+                }
+                Err(json_err) => Error::from(json_err),
+            })
+        }
+    }
+}
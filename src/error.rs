@@ -4,12 +4,15 @@ use std::io;
 use std::num::ParseIntError;
 use reqwest;
 use serde_json;
+use serde_qs;
 
 /// An error encountered when communicating with the Stripe API.
 #[derive(Debug)]
 pub enum Error {
     /// An error reported by Stripe.
     Stripe(RequestError),
+    /// An error reported by Stripe in response to an OAuth request.
+    OAuth(OAuthError),
     /// A networking error communicating with the Stripe server.
     Http(reqwest::Error),
     /// An error reading the response body.
@@ -23,6 +26,7 @@ impl fmt::Display for Error {
         f.write_str(error::Error::description(self))?;
         match *self {
             Error::Stripe(ref err) => write!(f, ": {}", err),
+            Error::OAuth(ref err) => write!(f, ": {}", err),
             Error::Http(ref err) => write!(f, ": {}", err),
             Error::Io(ref err) => write!(f, ": {}", err),
             Error::Conversion(ref err) => write!(f, ": {}", err),
@@ -34,6 +38,7 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::Stripe(_) => "error reported by stripe",
+            Error::OAuth(_) => "error reported by stripe connect",
             Error::Http(_) => "error communicating with stripe",
             Error::Io(_) => "error reading response from stripe",
             Error::Conversion(_) => "error converting between wire format and Rust types",
@@ -43,6 +48,7 @@ impl error::Error for Error {
     fn cause(&self) -> Option<&error::Error> {
         match *self {
             Error::Stripe(ref err) => Some(err),
+            Error::OAuth(ref err) => Some(err),
             Error::Http(ref err) => Some(err),
             Error::Io(ref err) => Some(err),
             Error::Conversion(ref err) => Some(&**err),
@@ -56,6 +62,18 @@ impl From<RequestError> for Error {
     }
 }
 
+impl From<OAuthError> for Error {
+    fn from(err: OAuthError) -> Error {
+        Error::OAuth(err)
+    }
+}
+
+impl From<serde_qs::Error> for Error {
+    fn from(err: serde_qs::Error) -> Error {
+        Error::Conversion(Box::new(err))
+    }
+}
+
 impl From<reqwest::Error> for Error {
     fn from(err: reqwest::Error) -> Error {
         Error::Http(err)
@@ -161,6 +179,36 @@ impl error::Error for RequestError {
     }
 }
 
+/// An error reported by stripe in response to an OAuth request.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#post-token-errors.
+#[derive(Debug, Deserialize)]
+pub struct OAuthError {
+    /// The type of error returned (eg. "invalid_grant").
+    pub error: String,
+
+    /// A human-readable message providing more details about the error.
+    pub error_description: Option<String>,
+}
+
+impl fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.error)?;
+        if let Some(ref description) = self.error_description {
+            write!(f, ": {}", description)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for OAuthError {
+    fn description(&self) -> &str {
+        self.error_description.as_ref().map(|s| s.as_str()).unwrap_or(
+            "oauth error",
+        )
+    }
+}
+
 /// An error encountered when communicating with the Stripe API webhooks.
 #[derive(Debug)]
 pub enum WebhookError {
@@ -56,14 +56,16 @@ extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 extern crate serde_json;
+extern crate serde_qs;
 extern crate sha2;
 
 mod client;
 mod error;
+#[macro_use]
 mod params;
 mod resources;
 
 pub use client::Client;
-pub use error::{Error, ErrorCode, ErrorType, RequestError};
-pub use params::{List, Metadata, RangeBounds, RangeQuery, Timestamp};
+pub use error::{Error, ErrorCode, ErrorType, OAuthError, RequestError};
+pub use params::{Identifiable, List, Metadata, RangeBounds, RangeQuery, Timestamp};
 pub use resources::*;
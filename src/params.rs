@@ -1,3 +1,7 @@
+use error::Error;
+use resources::{Account, ApplicationFee, ApplicationFeeRefund, BalanceTransaction, Dispute, File, InvoiceLineItem,
+                Order, OrderReturn, PaymentIntent, Payout, Plan, Product, Review, ScheduledQueryRun, Sku, Source,
+                SourceTransaction, Subscription, SubscriptionItem, Transfer, TransferReversal, UsageRecordSummary};
 use std::collections::HashMap;
 
 #[derive(Debug, Deserialize)]
@@ -8,6 +12,115 @@ pub struct List<T> {
     pub url: String,
 }
 
+/// A resource which can be used as a cursor when paginating a list (ie. its id can be used as `starting_after`).
+pub trait Identifiable {
+    fn id(&self) -> &str;
+}
+
+macro_rules! impl_identifiable {
+    ($($resource:ident),*) => {
+        $(impl Identifiable for $resource {
+            fn id(&self) -> &str {
+                &self.id
+            }
+        })*
+    };
+}
+
+impl_identifiable!(
+    Account,
+    ApplicationFee,
+    ApplicationFeeRefund,
+    BalanceTransaction,
+    Dispute,
+    File,
+    InvoiceLineItem,
+    Order,
+    OrderReturn,
+    PaymentIntent,
+    Payout,
+    Plan,
+    Product,
+    Review,
+    ScheduledQueryRun,
+    Sku,
+    Source,
+    SourceTransaction,
+    Subscription,
+    SubscriptionItem,
+    Transfer,
+    TransferReversal,
+    UsageRecordSummary
+);
+
+/// Defines an enum of the string values of a response field, which deserializes values
+/// not known to this crate as `Other` instead of failing.
+macro_rules! string_enum {
+    ($(#[$attr:meta])* pub enum $name:ident { $($variant:ident => $value:tt,)* }) => {
+        $(#[$attr])*
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum $name {
+            $($variant,)*
+            /// A value not known to this crate.
+            Other(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match *self {
+                    $($name::$variant => $value,)*
+                    $name::Other(ref other) => other,
+                }
+            }
+        }
+
+        impl<'a> From<&'a str> for $name {
+            fn from(value: &'a str) -> $name {
+                match value {
+                    $($value => $name::$variant,)*
+                    other => $name::Other(other.to_owned()),
+                }
+            }
+        }
+
+        impl ::serde::Serialize for $name {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<$name, D::Error> {
+                let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+                Ok($name::from(value.as_str()))
+            }
+        }
+    };
+}
+
+impl<T: Identifiable> List<T> {
+    /// Fetches the remaining pages of the list and returns every item, in order.
+    ///
+    /// The `next_page` closure receives the id of the last item fetched so far and
+    /// should request the next page using it as the `starting_after` param.
+    pub fn paginate<F>(self, mut next_page: F) -> Result<Vec<T>, Error>
+    where
+        F: FnMut(&str) -> Result<List<T>, Error>,
+    {
+        let mut data = self.data;
+        let mut has_more = self.has_more;
+        while has_more {
+            let page = match data.last() {
+                Some(last) => next_page(last.id())?,
+                None => break,
+            };
+            has_more = page.has_more;
+            data.extend(page.data);
+        }
+        Ok(data)
+    }
+}
+
 pub type Metadata = HashMap<String, String>;
 pub type Timestamp = i64;
 
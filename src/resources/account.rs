@@ -1,6 +1,7 @@
- use params::{List, Metadata, Timestamp};
-use resources::BankAccount;
-use serde_json as json;
+use error::Error;
+use client::Client;
+use params::{List, Metadata, RangeQuery, Timestamp};
+use resources::{Address, Currency, Deleted, ExternalAccount};
 
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct DeclineChargeDetails {
@@ -30,17 +31,216 @@ pub struct TOSAcceptanceDetails {
     pub user_agent: Option<String>,
 }
 
-/// The set of parameters that can be used when creating an account for users.
+/// An enum representing the possible values of an `Account`'s `type` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountType {
+    Custom,
+    Express,
+    Standard,
+}
+
+/// An enum representing the possible values of a `LegalEntity`'s `type` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LegalEntityType {
+    Company,
+    Individual,
+}
+
+/// An enum representing the possible values of a `LegalEntityVerification`'s `status` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdentityVerificationStatus {
+    Pending,
+    Unverified,
+    Verified,
+}
+
+/// An enum representing the reasons for which an account can be rejected.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountRejectReason {
+    Fraud,
+    Other,
+    TermsOfService,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct DateOfBirth {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub month: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<u32>,
+}
+
+/// The identity verification of a legal entity or one of its additional owners.
+///
+/// For more details see https://stripe.com/docs/api#account_object-legal_entity-verification.
+#[derive(Debug, Deserialize)]
+pub struct LegalEntityVerification {
+    pub details: Option<String>,
+    pub details_code: Option<String>,
+    pub document: Option<String>, // the id of an identity document `File`
+    pub status: IdentityVerificationStatus,
+}
+
+/// An additional owner of a company, as required for some countries.
+#[derive(Debug, Deserialize)]
+pub struct AdditionalOwner {
+    pub address: Option<Address>,
+    pub dob: Option<DateOfBirth>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub verification: Option<LegalEntityVerification>,
+}
+
+/// The individual or business that owns an account.
+///
+/// For more details see https://stripe.com/docs/api#account_object-legal_entity.
+#[derive(Debug, Deserialize)]
+pub struct LegalEntity {
+    pub additional_owners: Option<Vec<AdditionalOwner>>,
+    pub address: Option<Address>,
+    pub business_name: Option<String>,
+    pub business_tax_id_provided: Option<bool>,
+    pub dob: Option<DateOfBirth>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub personal_address: Option<Address>,
+    pub personal_id_number_provided: Option<bool>,
+    pub phone_number: Option<String>,
+    pub ssn_last_4_provided: Option<bool>,
+    #[serde(rename = "type")]
+    pub entity_type: Option<LegalEntityType>,
+    pub verification: Option<LegalEntityVerification>,
+}
+
+/// The information that is still required to enable charges and payouts for an account.
+///
+/// For more details see https://stripe.com/docs/api#account_object-verification.
+#[derive(Debug, Deserialize)]
+pub struct AccountVerification {
+    pub disabled_reason: Option<String>,
+    pub due_by: Option<Timestamp>,
+    pub fields_needed: Vec<String>, // eg. "legal_entity.dob.day"
+}
+
+/// The set of parameters that can be used to describe an additional owner of a legal entity.
+#[derive(Default, Serialize)]
+pub struct AdditionalOwnerParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob: Option<DateOfBirth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+}
+
+/// The set of parameters that can be used to upload an identity document for a legal entity.
+#[derive(Default, Serialize)]
+pub struct LegalEntityVerificationParams<'a> {
+    pub document: &'a str, // the id of an identity document `File`
+}
+
+/// The set of parameters that can be used when creating or updating the legal entity of an account.
+///
+/// For more details see https://stripe.com/docs/api#update_account-legal_entity.
+#[derive(Default, Serialize)]
+pub struct LegalEntityParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub additional_owners: Option<Vec<AdditionalOwnerParams<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_tax_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dob: Option<DateOfBirth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_address: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub personal_id_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssn_last_4: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_type: Option<LegalEntityType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<LegalEntityVerificationParams<'a>>,
+}
+
+/// The set of parameters that can be used when creating or updating an account.
+///
+/// The `account_type` and `country` can only be set when creating an account.
 ///
-/// For more details see https://stripe.com/docs/api#create_account.
-#[derive(Serialize)]
+/// For more details see https://stripe.com/docs/api#create_account and https://stripe.com/docs/api#update_account.
+#[derive(Default, Serialize)]
 pub struct AccountParams<'a> {
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_type: Option<AccountType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub country: Option<&'a str>, // (country the account holder resides in)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<&'a str>, // (required if account type is standard)
-    #[serde(rename = "type")]
-    pub account_type: &'static str,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub business_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub debit_negative_balances: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decline_charge_on: Option<DeclineChargeDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_entity: Option<LegalEntityParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout_schedule: Option<PayoutScheduleDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout_statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_phone: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub support_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tos_acceptance: Option<TOSAcceptanceDetails>,
+}
+
+/// The set of parameters that can be used when listing connected accounts.
+///
+/// For more details see https://stripe.com/docs/api#list_accounts.
+#[derive(Default, Serialize)]
+pub struct AccountListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
 }
 
 /// The resource representing a Stripe account.
@@ -50,29 +250,85 @@ pub struct AccountParams<'a> {
 pub struct Account {
     pub id: String,
     pub object: String,
-    pub business_name: String,
+    pub business_name: Option<String>,
     pub business_url: Option<String>,
-    pub charges_enabed: bool,
+    pub charges_enabled: bool,
     pub country: String,
     pub debit_negative_balances: Option<bool>,
     pub decline_charge_on: Option<DeclineChargeDetails>,
     pub default_currency: String,
     pub details_submitted: bool,
-    pub display_name: String,
-    pub email: String,
-    pub external_accounts: List<BankAccount>,
-    pub legal_entity: Option<json::Value>,
+    pub display_name: Option<String>,
+    pub email: Option<String>,
+    pub external_accounts: List<ExternalAccount>,
+    pub legal_entity: Option<LegalEntity>,
     pub metadata: Metadata,
     pub payout_schedule: Option<PayoutScheduleDetails>,
     pub payout_statement_descriptor: Option<String>,
     pub payouts_enabled: bool,
     pub product_description: Option<String>,
-    pub statement_descriptor: String,
-    pub support_email: String,
-    pub support_phone: String,
-    pub timezone: String,
+    pub statement_descriptor: Option<String>,
+    pub support_email: Option<String>,
+    pub support_phone: Option<String>,
+    pub timezone: Option<String>,
     pub tos_acceptance: Option<TOSAcceptanceDetails>, // (who accepted Stripe's terms of service)
     #[serde(rename = "type")]
-    pub account_type: Option<String>, // (Stripe, Custom, or Express)
-    pub verification: Option<json::Value>,
+    pub account_type: Option<AccountType>,
+    pub verification: Option<AccountVerification>,
+}
+
+impl Account {
+    /// Creates a new connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_account.
+    pub fn create(client: &Client, params: AccountParams) -> Result<Account, Error> {
+        client.post_with_params("/accounts", params)
+    }
+
+    /// Retrieves the details of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_account.
+    pub fn retrieve(client: &Client, account_id: &str) -> Result<Account, Error> {
+        client.get(&format!("/accounts/{}", account_id))
+    }
+
+    /// Retrieves the details of the account the client is authenticated as.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_account.
+    pub fn retrieve_current(client: &Client) -> Result<Account, Error> {
+        client.get("/account")
+    }
+
+    /// Updates a connected account's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_account.
+    pub fn update(client: &Client, account_id: &str, params: AccountParams) -> Result<Account, Error> {
+        client.post_with_params(&format!("/accounts/{}", account_id), params)
+    }
+
+    /// Deletes a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_account.
+    pub fn delete(client: &Client, account_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/accounts/{}", account_id))
+    }
+
+    /// Rejects a connected account, disabling its charges and payouts.
+    ///
+    /// For more details see https://stripe.com/docs/api#reject_account.
+    pub fn reject(client: &Client, account_id: &str, reason: AccountRejectReason) -> Result<Account, Error> {
+        #[derive(Serialize)]
+        struct Params {
+            reason: AccountRejectReason,
+        }
+
+        client.post_with_params(&format!("/accounts/{}/reject", account_id), Params { reason: reason })
+    }
+
+    /// Lists the connected accounts of the platform.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_accounts.
+    pub fn list(client: &Client, params: AccountListParams) -> Result<List<Account>, Error> {
+        client.get_with_params("/accounts", params)
+    }
 }
@@ -0,0 +1,53 @@
+use error::Error;
+use client::Client;
+use params::Timestamp;
+
+/// An enum representing the possible values of an `AccountLinkParams`'s `type` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountLinkType {
+    AccountOnboarding,
+    AccountUpdate,
+}
+
+/// An enum representing the possible values of an `AccountLinkParams`'s `collect` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountLinkCollect {
+    CurrentlyDue,
+    EventuallyDue,
+}
+
+/// The set of parameters that can be used when creating an account link.
+///
+/// For more details see https://stripe.com/docs/api#create_account_link.
+#[derive(Serialize)]
+pub struct AccountLinkParams<'a> {
+    pub account: &'a str,
+    pub refresh_url: &'a str, // NOTE: the user is redirected here if the link expired or was already visited
+    pub return_url: &'a str,
+    #[serde(rename = "type")]
+    pub link_type: AccountLinkType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collect: Option<AccountLinkCollect>,
+}
+
+/// The resource representing a Stripe account link.
+///
+/// For more details see https://stripe.com/docs/api#account_link_object.
+#[derive(Debug, Deserialize)]
+pub struct AccountLink {
+    pub object: String,
+    pub created: Timestamp,
+    pub expires_at: Timestamp,
+    pub url: String,
+}
+
+impl AccountLink {
+    /// Creates a single-use link that takes a connected account through onboarding or updating its details.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_account_link.
+    pub fn create(client: &Client, params: AccountLinkParams) -> Result<AccountLink, Error> {
+        client.post_with_params("/account_links", params)
+    }
+}
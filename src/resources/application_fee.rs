@@ -1,5 +1,24 @@
-use params::{List, Timestamp};
-use resources::{Currency, Refund};
+use error::Error;
+use client::Client;
+use params::{List, RangeQuery, Timestamp};
+use resources::{ApplicationFeeRefund, Currency};
+
+/// The set of parameters that can be used when listing application fees.
+///
+/// For more details see https://stripe.com/docs/api#list_application_fees.
+#[derive(Default, Serialize)]
+pub struct ApplicationFeeListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
 
 /// The resource representing a Stripe application fee.
 ///
@@ -19,5 +38,21 @@ pub struct ApplicationFee {
     pub livemode: bool,
     pub originating_transaction: Option<String>,
     pub refunded: bool,
-    pub refunds: List<Refund>,
+    pub refunds: List<ApplicationFeeRefund>,
+}
+
+impl ApplicationFee {
+    /// Retrieves the details of an application fee.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_application_fee.
+    pub fn retrieve(client: &Client, fee_id: &str) -> Result<ApplicationFee, Error> {
+        client.get(&format!("/application_fees/{}", fee_id))
+    }
+
+    /// Lists the application fees collected by the platform.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_application_fees.
+    pub fn list(client: &Client, params: ApplicationFeeListParams) -> Result<List<ApplicationFee>, Error> {
+        client.get_with_params("/application_fees", params)
+    }
 }
@@ -1,6 +1,43 @@
-use params::{Timestamp, Metadata};
+use error::Error;
+use client::Client;
+use params::{List, Metadata, Timestamp};
 use resources::Currency;
 
+/// The set of parameters that can be used when refunding an application fee.
+///
+/// If `amount` is `None`, the entire remaining amount of the fee is refunded.
+///
+/// For more details see https://stripe.com/docs/api#create_fee_refund.
+#[derive(Default, Serialize)]
+pub struct ApplicationFeeRefundParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when updating an application fee refund.
+///
+/// For more details see https://stripe.com/docs/api#update_fee_refund.
+#[derive(Default, Serialize)]
+pub struct ApplicationFeeRefundUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing the refunds of an application fee.
+///
+/// For more details see https://stripe.com/docs/api#list_fee_refunds.
+#[derive(Default, Serialize)]
+pub struct ApplicationFeeRefundListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
 /// The resource representing a Stripe application fee refund.
 ///
 /// For more details see https://stripe.com/docs/api#fee_refunds.
@@ -15,3 +52,46 @@ pub struct ApplicationFeeRefund {
     pub fee: String,
     pub metadata: Metadata,
 }
+
+impl ApplicationFeeRefund {
+    /// Refunds all or part of an application fee.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_fee_refund.
+    pub fn create(
+        client: &Client,
+        fee_id: &str,
+        params: ApplicationFeeRefundParams,
+    ) -> Result<ApplicationFeeRefund, Error> {
+        client.post_with_params(&format!("/application_fees/{}/refunds", fee_id), params)
+    }
+
+    /// Retrieves the details of an application fee refund.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_fee_refund.
+    pub fn retrieve(client: &Client, fee_id: &str, refund_id: &str) -> Result<ApplicationFeeRefund, Error> {
+        client.get(&format!("/application_fees/{}/refunds/{}", fee_id, refund_id))
+    }
+
+    /// Updates an application fee refund's metadata.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_fee_refund.
+    pub fn update(
+        client: &Client,
+        fee_id: &str,
+        refund_id: &str,
+        params: ApplicationFeeRefundUpdateParams,
+    ) -> Result<ApplicationFeeRefund, Error> {
+        client.post_with_params(&format!("/application_fees/{}/refunds/{}", fee_id, refund_id), params)
+    }
+
+    /// Lists the refunds of an application fee.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_fee_refunds.
+    pub fn list(
+        client: &Client,
+        fee_id: &str,
+        params: ApplicationFeeRefundListParams,
+    ) -> Result<List<ApplicationFeeRefund>, Error> {
+        client.get_with_params(&format!("/application_fees/{}/refunds", fee_id), params)
+    }
+}
@@ -1,15 +1,83 @@
-use serde_json as json;
-use params::{List, Timestamp};
-use resources::{Currency, Source};
+use error::Error;
+use client::Client;
+use params::{List, RangeQuery, Timestamp};
+use resources::Currency;
+
+/// An enum representing the possible values of a `BalanceTransaction`'s `status` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BalanceTransactionStatus {
+    Available,
+    Pending,
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `BalanceTransaction`'s `type` field.
+    pub enum BalanceTransactionType {
+        Adjustment => "adjustment",
+        ApplicationFee => "application_fee",
+        ApplicationFeeRefund => "application_fee_refund",
+        Charge => "charge",
+        NetworkCost => "network_cost",
+        Payment => "payment",
+        PaymentFailureRefund => "payment_failure_refund",
+        PaymentRefund => "payment_refund",
+        Payout => "payout",
+        PayoutCancel => "payout_cancel",
+        PayoutFailure => "payout_failure",
+        Refund => "refund",
+        RefundFailure => "refund_failure",
+        ReserveTransaction => "reserve_transaction",
+        ReservedFunds => "reserved_funds",
+        StripeFee => "stripe_fee",
+        StripeFxFee => "stripe_fx_fee",
+        TaxFee => "tax_fee",
+        Topup => "topup",
+        TopupReversal => "topup_reversal",
+        Transfer => "transfer",
+        TransferCancel => "transfer_cancel",
+        TransferFailure => "transfer_failure",
+        TransferRefund => "transfer_refund",
+        Validation => "validation",
+    }
+}
+
+/// An enum representing the possible values of a `FeeDetails`'s `type` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeType {
+    ApplicationFee,
+    StripeFee,
+    Tax,
+}
 
 #[derive(Debug, Deserialize)]
 pub struct FeeDetails {
-    pub amount: u64,
-    pub application: String,
+    pub amount: i64,
+    pub application: Option<String>,
     pub currency: Currency,
-    pub description: String,
+    pub description: Option<String>,
     #[serde(rename = "type")]
-    pub fee_type: String, // (application_fee, stripe_fee, or tax)
+    pub fee_type: FeeType,
+}
+
+/// The breakdown of a balance amount by the type of payment source the funds came from.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct BalanceSourceTypes {
+    pub bank_account: i64,
+    pub bitcoin_receiver: i64,
+    pub card: i64,
+}
+
+/// The funds available or pending in a single currency.
+///
+/// For more details see https://stripe.com/docs/api#balance_object-available.
+#[derive(Debug, Deserialize)]
+pub struct BalanceAmount {
+    pub amount: i64,
+    pub currency: Currency,
+    pub source_types: Option<BalanceSourceTypes>,
 }
 
 /// The resource representing a Stripe account balance.
@@ -18,10 +86,46 @@ pub struct FeeDetails {
 #[derive(Debug, Deserialize)]
 pub struct Balance {
     pub object: String,
-    pub available: Vec<json::Value>,
-    pub connect_reserved: Vec<json::Value>,
+    pub available: Vec<BalanceAmount>,
+    #[serde(default)]
+    pub connect_reserved: Vec<BalanceAmount>,
     pub livemode: bool,
-    pub pending: Vec<json::Value>,
+    pub pending: Vec<BalanceAmount>,
+}
+
+impl Balance {
+    /// Retrieves the current account balance.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_balance.
+    pub fn retrieve(client: &Client) -> Result<Balance, Error> {
+        client.get("/balance")
+    }
+}
+
+/// The set of parameters that can be used when listing balance transactions.
+///
+/// For more details see https://stripe.com/docs/api#balance_history.
+#[derive(Default, Serialize)]
+pub struct BalanceTransactionListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_on: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payout: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transaction_type: Option<BalanceTransactionType>,
 }
 
 /// The resource representing a Stripe balance transaction.
@@ -31,16 +135,33 @@ pub struct Balance {
 pub struct BalanceTransaction {
     pub id: String,
     pub object: String,
-    pub amount: u64,
+    pub amount: i64,
     pub available_on: Timestamp,
     pub created: Timestamp,
     pub currency: Currency,
-    pub description: String,
-    pub fee: u64,
-    pub fee_details: List<FeeDetails>,
-    pub net: u64,
-    pub source: Source,
-    pub status: String,
+    pub description: Option<String>,
+    pub exchange_rate: Option<f64>,
+    pub fee: i64,
+    pub fee_details: Vec<FeeDetails>,
+    pub net: i64,
+    pub source: Option<String>, // the id of the charge, refund, payout, etc.
+    pub status: BalanceTransactionStatus,
     #[serde(rename = "type")]
-    pub transaction_type: String,
+    pub transaction_type: BalanceTransactionType,
+}
+
+impl BalanceTransaction {
+    /// Retrieves the details of a balance transaction.
+    ///
+    /// For more details see https://stripe.com/docs/api#balance_transaction_retrieve.
+    pub fn retrieve(client: &Client, transaction_id: &str) -> Result<BalanceTransaction, Error> {
+        client.get(&format!("/balance/history/{}", transaction_id))
+    }
+
+    /// Lists the balance transactions that have contributed to the account balance.
+    ///
+    /// For more details see https://stripe.com/docs/api#balance_history.
+    pub fn list(client: &Client, params: BalanceTransactionListParams) -> Result<List<BalanceTransaction>, Error> {
+        client.get_with_params("/balance/history", params)
+    }
 }
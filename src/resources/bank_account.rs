@@ -1,24 +1,56 @@
 use params::Metadata;
 use resources::Currency;
 
+/// The set of parameters that can be used to describe a bank account,
+/// when it is not tokenized client-side.
+///
+/// For more details see https://stripe.com/docs/api#account_create_bank_account.
+#[derive(Debug, Serialize)]
+pub struct BankAccountParams<'a> {
+    pub object: &'static str, // must be "bank_account"
+    pub country: &'a str, // eg. "US"
+    pub currency: Currency,
+    pub account_number: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_type: Option<&'a str>, // (individual or company)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_number: Option<&'a str>,
+}
+
+impl<'a> Default for BankAccountParams<'a> {
+    fn default() -> Self {
+        BankAccountParams {
+            object: "bank_account",
+            country: "",
+            currency: Currency::default(),
+            account_number: "",
+            account_holder_name: None,
+            account_holder_type: None,
+            routing_number: None,
+        }
+    }
+}
+
 /// The resource representing a Stripe bank account.
 ///
 /// For more details see https://stripe.com/docs/api#customer_bank_account_object.
 #[derive(Debug, Deserialize)]
 pub struct BankAccount {
     pub id: String,
-    pub object: String,
-    pub account: String,
-    pub account_holder_name: String,
-    pub account_holder_type: String, // (individual or company)
-    pub bank_name: String,
+    pub account: Option<String>, // NOTE: only present for the external accounts of a connected account
+    pub account_holder_name: Option<String>,
+    pub account_holder_type: Option<String>, // (individual or company)
+    pub bank_name: Option<String>,
     pub country: String,
     pub currency: Currency,
-    pub customer: String,
-    pub default_for_currency: bool,
+    pub customer: Option<String>, // NOTE: only present for the sources of a customer
+    pub default_for_currency: Option<bool>,
     pub fingerprint: String,
     pub last4: String,
+    #[serde(default)]
     pub metadata: Metadata,
-    pub routing_number: String,
+    pub routing_number: Option<String>,
     pub status: String, // (new, validated, verified, verification_failed, errored)
 }
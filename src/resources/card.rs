@@ -1,3 +1,6 @@
+use params::Metadata;
+use resources::Currency;
+
 #[derive(Debug, Serialize)]
 pub struct CardParams<'a> {
     pub object: &'static str, // must be "card"
@@ -25,6 +28,7 @@ impl<'a> Default for CardParams<'a> {
 #[derive(Debug, Deserialize)]
 pub struct Card {
     pub id: String,
+    pub account: Option<String>, // NOTE: only present for the external accounts of a connected account
     pub address_city: Option<String>,
     pub address_country: Option<String>,
     pub address_line1: Option<String>,
@@ -35,11 +39,16 @@ pub struct Card {
     pub address_zip_check: Option<String>, // (pass, fail, unavailable, unchecked)
     pub brand: String, // (Visa, American Express, MasterCard, Discover, JCB, Diners Club, or Unknown)
     pub country: String, // eg. "US"
+    pub currency: Option<Currency>, // NOTE: only present for the external accounts of a connected account
     pub customer: Option<String>,
     pub cvc_check: Option<String>, // (pass, fail, unavailable, unchecked)
+    pub default_for_currency: Option<bool>,
     pub exp_month: u32,
     pub exp_year: u32,
     pub fingerprint: String,
     pub funding: String, // (credit, debit, prepaid, unknown)
     pub last4: String,
+    #[serde(default)]
+    pub metadata: Metadata,
+    pub name: Option<String>,
 }
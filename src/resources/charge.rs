@@ -1,7 +1,7 @@
 use client::Client;
 use error::{Error, ErrorCode};
 use params::{List, Metadata, RangeQuery, Timestamp};
-use resources::{Address, Currency, CustomerSourceParam, Refund, Source};
+use resources::{Address, Currency, CustomerSourceParam, PaymentSource, Refund};
 
 #[derive(Debug, Deserialize)]
 pub struct ChargeOutcome {
@@ -176,12 +176,14 @@ pub struct Charge {
     pub order: Option<String>,
     pub outcome: Option<ChargeOutcome>,
     pub paid: bool,
+    pub payment_intent: Option<String>,
+    pub payment_method: Option<String>,
     pub receipt_email: Option<String>,
     pub receipt_number: Option<String>,
     pub refunded: bool,
     pub refunds: List<Refund>,
     pub shipping: Option<ShippingDetails>,
-    pub source: Source,
+    pub source: Option<PaymentSource>, // NOTE: None for charges made with a payment method
     pub source_transfer: Option<String>,
     pub statement_descriptor: Option<String>,
     pub status: String, // (succeeded, pending, failed)
@@ -1,7 +1,7 @@
 use client::Client;
 use error::Error;
 use params::{List, Metadata, RangeQuery, Timestamp};
-use resources::{Address, CardParams, Currency, Deleted, Discount, Source, Subscription};
+use resources::{Address, BankAccount, CardParams, Currency, Deleted, Discount, PaymentSource, SourceFilter, Subscription};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct CustomerShippingDetails {
@@ -58,6 +58,63 @@ pub struct CustomerListParams<'a> {
     pub starting_after: Option<&'a str>,
 }
 
+/// The set of parameters that can be used when listing the payment sources of a customer.
+///
+/// For more details see https://stripe.com/docs/api#list_cards and https://stripe.com/docs/api#customer_list_bank_accounts.
+#[derive(Default, Serialize)]
+pub struct CustomerSourceListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(flatten)]
+    pub filter: Option<SourceFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when updating a card or bank account of a customer.
+///
+/// The `address_*`, `exp_*` and `name` fields only apply to cards,
+/// while the `account_holder_*` fields only apply to bank accounts.
+///
+/// For more details see https://stripe.com/docs/api#update_card and https://stripe.com/docs/api#customer_update_bank_account.
+#[derive(Default, Serialize)]
+pub struct CustomerSourceUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_type: Option<&'a str>, // (individual or company)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_city: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_country: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line1: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line2: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_state: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_zip: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_month: Option<&'a str>, // eg. "12"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_year: Option<&'a str>, // eg. "17" or 2017"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when verifying a bank account of a customer.
+///
+/// For more details see https://stripe.com/docs/api#customer_verify_bank_account.
+#[derive(Debug, Serialize)]
+pub struct BankAccountVerifyParams {
+    pub amounts: [u64; 2], // in cents
+}
+
 /// The resource representing a Stripe customer.
 ///
 /// For more details see https://stripe.com/docs/api#customers.
@@ -76,7 +133,7 @@ pub struct Customer {
     pub livemode: bool,
     pub metadata: Metadata,
     pub shipping: Option<CustomerShippingDetails>,
-    pub sources: List<Source>,
+    pub sources: List<PaymentSource>,
     pub subscriptions: List<Subscription>,
 }
 
@@ -116,7 +173,7 @@ impl Customer {
         client.get_with_params("/customers", params)
     }
 
-    pub fn attach_source(client: &Client, customer_id: &str, source: CustomerSourceParam) -> Result<Source, Error> {
+    pub fn attach_source(client: &Client, customer_id: &str, source: CustomerSourceParam) -> Result<PaymentSource, Error> {
         #[derive(Debug, Serialize)]
         struct Params<'a> {
             source: CustomerSourceParam<'a>,
@@ -131,4 +188,54 @@ impl Customer {
     pub fn detach_source(client: &Client, customer_id: &str, source_id: &str) -> Result<Deleted, Error> {
         client.delete(&format!("/customers/{}/sources/{}", customer_id, source_id))
     }
+
+    /// Lists the cards and bank accounts of a customer, optionally filtered by object type.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_cards and https://stripe.com/docs/api#customer_list_bank_accounts.
+    pub fn list_sources(
+        client: &Client,
+        customer_id: &str,
+        params: CustomerSourceListParams,
+    ) -> Result<List<PaymentSource>, Error> {
+        client.get_with_params(&format!("/customers/{}/sources", customer_id), params)
+    }
+
+    /// Retrieves a card or bank account of a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_card and https://stripe.com/docs/api#customer_retrieve_bank_account.
+    pub fn retrieve_source(client: &Client, customer_id: &str, source_id: &str) -> Result<PaymentSource, Error> {
+        client.get(&format!("/customers/{}/sources/{}", customer_id, source_id))
+    }
+
+    /// Updates a card or bank account of a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_card and https://stripe.com/docs/api#customer_update_bank_account.
+    pub fn update_source(
+        client: &Client,
+        customer_id: &str,
+        source_id: &str,
+        params: CustomerSourceUpdateParams,
+    ) -> Result<PaymentSource, Error> {
+        client.post_with_params(&format!("/customers/{}/sources/{}", customer_id, source_id), params)
+    }
+
+    /// Sets the source that is used by default when charging the customer.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_customer-default_source.
+    pub fn set_default_source(client: &Client, customer_id: &str, source_id: &str) -> Result<Customer, Error> {
+        let params = CustomerParams { default_source_id: Some(source_id), ..Default::default() };
+        Customer::update(client, customer_id, params)
+    }
+
+    /// Verifies a bank account of a customer with the amounts of the two micro-deposits sent to it.
+    ///
+    /// For more details see https://stripe.com/docs/api#customer_verify_bank_account.
+    pub fn verify_bank_account(
+        client: &Client,
+        customer_id: &str,
+        bank_account_id: &str,
+        params: BankAccountVerifyParams,
+    ) -> Result<BankAccount, Error> {
+        client.post_with_params(&format!("/customers/{}/sources/{}/verify", customer_id, bank_account_id), params)
+    }
 }
@@ -1,6 +1,44 @@
-use params::{Metadata, Timestamp};
+use error::Error;
+use client::Client;
+use params::{List, Metadata, RangeQuery, Timestamp};
 use resources::{Currency, BalanceTransaction};
 
+string_enum! {
+    /// An enum representing the possible values of a `Dispute`'s `reason` field.
+    ///
+    /// For more details see https://stripe.com/docs/disputes/categories.
+    pub enum DisputeReason {
+        BankCannotProcess => "bank_cannot_process",
+        CheckReturned => "check_returned",
+        CreditNotProcessed => "credit_not_processed",
+        CustomerInitiated => "customer_initiated",
+        DebitNotAuthorized => "debit_not_authorized",
+        Duplicate => "duplicate",
+        Fraudulent => "fraudulent",
+        General => "general",
+        IncorrectAccountDetails => "incorrect_account_details",
+        InsufficientFunds => "insufficient_funds",
+        ProductNotReceived => "product_not_received",
+        ProductUnacceptable => "product_unacceptable",
+        SubscriptionCanceled => "subscription_canceled",
+        Unrecognized => "unrecognized",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `Dispute`'s `status` field.
+    pub enum DisputeStatus {
+        ChargeRefunded => "charge_refunded",
+        Lost => "lost",
+        NeedsResponse => "needs_response",
+        UnderReview => "under_review",
+        WarningClosed => "warning_closed",
+        WarningNeedsResponse => "warning_needs_response",
+        WarningUnderReview => "warning_under_review",
+        Won => "won",
+    }
+}
+
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct EvidenceDetails {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -12,6 +50,9 @@ pub struct EvidenceDetails {
 
 /// The resource representing the evidence used to support a dispute.
 ///
+/// The documentation fields (eg. `receipt`, `customer_signature` and `shipping_documentation`)
+/// hold the id of a `File` uploaded with the `dispute_evidence` purpose.
+///
 /// For more details see https://stripe.com/docs/api#dispute_evidence_object.
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct DisputeEvidenceObject {
@@ -88,6 +129,69 @@ pub struct Dispute {
     pub is_charge_refundable: bool,
     pub livemode: bool,
     pub metadata: Metadata,
-    pub reason: Option<String>,
-    pub status: Option<String>,
+    pub reason: DisputeReason,
+    pub status: DisputeStatus,
+}
+
+/// The set of parameters that can be used when updating a dispute.
+///
+/// If `submit` is `Some(false)` the evidence is staged but not submitted to the bank,
+/// so that more evidence can be added later.
+///
+/// For more details see https://stripe.com/docs/api#update_dispute.
+#[derive(Default, Serialize)]
+pub struct DisputeParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<DisputeEvidenceObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<bool>, // NOTE: if None, Stripe assumes true
+}
+
+/// The set of parameters that can be used when listing disputes.
+///
+/// For more details see https://stripe.com/docs/api#list_disputes.
+#[derive(Default, Serialize)]
+pub struct DisputeListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub charge: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl Dispute {
+    /// Retrieves the details of a dispute.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_dispute.
+    pub fn retrieve(client: &Client, dispute_id: &str) -> Result<Dispute, Error> {
+        client.get(&format!("/disputes/{}", dispute_id))
+    }
+
+    /// Updates a dispute, usually to submit evidence.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_dispute.
+    pub fn update(client: &Client, dispute_id: &str, params: DisputeParams) -> Result<Dispute, Error> {
+        client.post_with_params(&format!("/disputes/{}", dispute_id), params)
+    }
+
+    /// Closes a dispute, acknowledging it as lost.
+    ///
+    /// For more details see https://stripe.com/docs/api#close_dispute.
+    pub fn close(client: &Client, dispute_id: &str) -> Result<Dispute, Error> {
+        client.post(&format!("/disputes/{}/close", dispute_id))
+    }
+
+    /// Lists all disputes.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_disputes.
+    pub fn list(client: &Client, params: DisputeListParams) -> Result<List<Dispute>, Error> {
+        client.get_with_params("/disputes", params)
+    }
 }
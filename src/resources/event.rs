@@ -110,6 +110,18 @@ pub enum EventType {
     OrderUpdated,
     #[serde(rename = "order_return.updated")]
     OrderReturnUpdated,
+    #[serde(rename = "payment_intent.amount_capturable_updated")]
+    PaymentIntentAmountCapturableUpdated,
+    #[serde(rename = "payment_intent.canceled")]
+    PaymentIntentCanceled,
+    #[serde(rename = "payment_intent.created")]
+    PaymentIntentCreated,
+    #[serde(rename = "payment_intent.payment_failed")]
+    PaymentIntentPaymentFailed,
+    #[serde(rename = "payment_intent.processing")]
+    PaymentIntentProcessing,
+    #[serde(rename = "payment_intent.succeeded")]
+    PaymentIntentSucceeded,
     #[serde(rename = "payout.canceled")]
     PayoutCanceled,
     #[serde(rename = "payout.created")]
@@ -190,12 +202,14 @@ pub enum EventObject {
     InvoiceItem(InvoiceItem),
     Order(Order),
     OrderReturn(OrderReturn),
+    PaymentIntent(PaymentIntent),
     Payout(Payout),
     Plan(Plan),
     Product(Product),
     Refund(Refund),
     Review(Review),
     Sku(Sku),
+    Source(Source),
     Subscription(Subscription),
     Transaction(Transaction),
     Transfer(Transfer),
@@ -0,0 +1,163 @@
+use error::Error;
+use client::Client;
+use params::{Identifiable, List, Metadata};
+use resources::{BankAccount, BankAccountParams, Card, CardParams, Deleted};
+
+/// A bank account or debit card that a connected account can be paid out to.
+///
+/// For more details see https://stripe.com/docs/api#external_accounts.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum ExternalAccount {
+    BankAccount(BankAccount),
+    Card(Card),
+}
+
+impl ExternalAccount {
+    /// Whether this is the default external account for its currency.
+    pub fn default_for_currency(&self) -> bool {
+        match *self {
+            ExternalAccount::BankAccount(ref bank_account) => bank_account.default_for_currency.unwrap_or(false),
+            ExternalAccount::Card(ref card) => card.default_for_currency.unwrap_or(false),
+        }
+    }
+}
+
+impl Identifiable for ExternalAccount {
+    fn id(&self) -> &str {
+        match *self {
+            ExternalAccount::BankAccount(ref bank_account) => &bank_account.id,
+            ExternalAccount::Card(ref card) => &card.id,
+        }
+    }
+}
+
+/// The kinds of objects that external accounts can be filtered by when listing them.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalAccountObject {
+    BankAccount,
+    Card,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum ExternalAccountParam<'a> {
+    Token(&'a str),
+    BankAccount(BankAccountParams<'a>),
+    Card(CardParams<'a>),
+}
+
+/// The set of parameters that can be used when creating an external account.
+///
+/// For more details see https://stripe.com/docs/api#account_create_bank_account and https://stripe.com/docs/api#account_create_card.
+#[derive(Serialize)]
+pub struct ExternalAccountParams<'a> {
+    pub external_account: ExternalAccountParam<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_for_currency: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when updating an external account.
+///
+/// The `account_holder_*` params only apply to bank accounts, and the others (except for
+/// `default_for_currency` and `metadata`) only apply to cards.
+///
+/// For more details see https://stripe.com/docs/api#account_update_bank_account and https://stripe.com/docs/api#account_update_card.
+#[derive(Default, Serialize)]
+pub struct ExternalAccountUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_holder_type: Option<&'a str>, // (individual or company)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_city: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_country: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line1: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_line2: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_state: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_zip: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_for_currency: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_month: Option<&'a str>, // eg. "12"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp_year: Option<&'a str>, // eg. "17" or 2017"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when listing external accounts.
+///
+/// For more details see https://stripe.com/docs/api#account_list_bank_accounts and https://stripe.com/docs/api#account_list_cards.
+#[derive(Default, Serialize)]
+pub struct ExternalAccountListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub object: Option<ExternalAccountObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+impl ExternalAccount {
+    /// Creates a new external account for a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#account_create_bank_account.
+    pub fn create(client: &Client, account_id: &str, params: ExternalAccountParams) -> Result<ExternalAccount, Error> {
+        client.post_with_params(&format!("/accounts/{}/external_accounts", account_id), params)
+    }
+
+    /// Retrieves the details of an external account of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#account_retrieve_bank_account.
+    pub fn retrieve(client: &Client, account_id: &str, external_account_id: &str) -> Result<ExternalAccount, Error> {
+        client.get(&format!("/accounts/{}/external_accounts/{}", account_id, external_account_id))
+    }
+
+    /// Updates an external account's properties, eg. to make it the default for its currency.
+    ///
+    /// For more details see https://stripe.com/docs/api#account_update_bank_account.
+    pub fn update(
+        client: &Client,
+        account_id: &str,
+        external_account_id: &str,
+        params: ExternalAccountUpdateParams,
+    ) -> Result<ExternalAccount, Error> {
+        client.post_with_params(
+            &format!("/accounts/{}/external_accounts/{}", account_id, external_account_id),
+            params,
+        )
+    }
+
+    /// Deletes an external account of a connected account.
+    ///
+    /// The default external account for a currency can't be deleted.
+    ///
+    /// For more details see https://stripe.com/docs/api#account_delete_bank_account.
+    pub fn delete(client: &Client, account_id: &str, external_account_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/accounts/{}/external_accounts/{}", account_id, external_account_id))
+    }
+
+    /// Lists the external accounts of a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#account_list_bank_accounts.
+    pub fn list(
+        client: &Client,
+        account_id: &str,
+        params: ExternalAccountListParams,
+    ) -> Result<List<ExternalAccount>, Error> {
+        client.get_with_params(&format!("/accounts/{}/external_accounts", account_id), params)
+    }
+}
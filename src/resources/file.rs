@@ -1,4 +1,40 @@
-use params::Timestamp;
+use std::io::{Read, Write};
+
+use error::Error;
+use client::Client;
+use params::{List, RangeQuery, Timestamp};
+use reqwest::multipart::{Form, Part};
+
+string_enum! {
+    /// An enum representing the possible values of a `File`'s `purpose` field.
+    pub enum FilePurpose {
+        BusinessLogo => "business_logo",
+        CustomerSignature => "customer_signature",
+        DisputeEvidence => "dispute_evidence",
+        FinanceReportRun => "finance_report_run",
+        IdentityDocument => "identity_document",
+        PciDocument => "pci_document",
+        SigmaScheduledQuery => "sigma_scheduled_query",
+        TaxDocumentUserUpload => "tax_document_user_upload",
+    }
+}
+
+/// The set of parameters that can be used when listing files.
+///
+/// For more details see https://stripe.com/docs/api#list_file_uploads.
+#[derive(Default, Serialize)]
+pub struct FileListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<FilePurpose>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
 
 /// The resource representing a Stripe file.
 ///
@@ -8,10 +44,50 @@ pub struct File {
     pub id: String,
     pub object: String,
     pub created: Timestamp,
-    pub purpose: String,
+    pub purpose: FilePurpose,
     pub size: u64,
     pub title: Option<String>,
     #[serde(rename = "type")]
-    pub file_type: String, // (csv, pdf, jpg, png)
-    pub url: String,
+    pub file_type: Option<String>, // (csv, pdf, jpg, png)
+    pub url: Option<String>,
+}
+
+impl File {
+    /// Uploads a file to Stripe, reading its contents from `file`.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_file_upload.
+    pub fn create<Str: Into<String>, R: Read + Send + 'static>(
+        client: &Client,
+        purpose: FilePurpose,
+        file_name: Str,
+        file: R,
+    ) -> Result<File, Error> {
+        let form = Form::new()
+            .text("purpose", purpose.as_str().to_owned())
+            .part("file", Part::reader(file).file_name(file_name.into()));
+        client.upload("/files", form)
+    }
+
+    /// Retrieves the details of a file.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_file_upload.
+    pub fn retrieve(client: &Client, file_id: &str) -> Result<File, Error> {
+        client.get(&format!("/files/{}", file_id))
+    }
+
+    /// Downloads the contents of a file, streaming them into `writer`.
+    ///
+    /// Returns the number of bytes written.
+    ///
+    /// For more details see https://stripe.com/docs/file-upload#downloading-a-file.
+    pub fn download<W: Write + ?Sized>(client: &Client, file_id: &str, writer: &mut W) -> Result<u64, Error> {
+        client.download(&format!("/files/{}/contents", file_id), writer)
+    }
+
+    /// Lists the files uploaded to the account.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_file_uploads.
+    pub fn list(client: &Client, params: FileListParams) -> Result<List<File>, Error> {
+        client.get_with_params("/files", params)
+    }
 }
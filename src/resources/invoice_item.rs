@@ -1,5 +1,79 @@
-use params::{Metadata, Timestamp};
-use resources::{Currency, Period, Plan};
+use error::Error;
+use client::Client;
+use params::{List, Metadata, RangeQuery, Timestamp};
+use resources::{Currency, Deleted, Period, Plan};
+
+/// The set of parameters that can be used when creating an invoice item.
+///
+/// Either `amount` or both `unit_amount` and `quantity` should be set when creating an invoice item.
+///
+/// For more details see https://stripe.com/docs/api#create_invoiceitem.
+#[derive(Default, Serialize)]
+pub struct InvoiceItemCreateParams<'a> {
+    pub customer: &'a str, // this is a required param
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discountable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period: Option<Period>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subscription: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+}
+
+/// The set of parameters that can be used when updating an invoice item.
+///
+/// For more details see https://stripe.com/docs/api#update_invoiceitem.
+#[derive(Default, Serialize)]
+pub struct InvoiceItemUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub discountable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub period: Option<Period>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_amount: Option<i64>,
+}
+
+/// The set of parameters that can be used when listing invoice items.
+///
+/// For more details see https://stripe.com/docs/api#list_invoiceitems.
+#[derive(Default, Serialize)]
+pub struct InvoiceItemListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invoice: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
 
 /// The resource representing a Stripe invoice item.
 ///
@@ -8,19 +82,61 @@ use resources::{Currency, Period, Plan};
 pub struct InvoiceItem {
     pub id: String,
     pub object: String,
-    pub amount: u64,
+    pub amount: i64,
     pub currency: Currency,
     pub customer: String,
     pub date: Timestamp,
-    pub description: String,
-    pub discountable: String,
-    pub invoice: String,
+    pub description: Option<String>,
+    pub discountable: bool,
+    pub invoice: Option<String>,
     pub livemode: bool,
     pub metadata: Metadata,
     pub period: Period,
     pub plan: Option<Plan>,
     pub proration: bool,
-    pub quantity: u64,
+    pub quantity: Option<u64>,
     pub subscription: Option<String>,
     pub subscription_item: Option<String>,
+    pub unit_amount: Option<i64>,
+}
+
+impl InvoiceItem {
+    /// Creates an invoice item.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_invoiceitem.
+    pub fn create(client: &Client, params: InvoiceItemCreateParams) -> Result<InvoiceItem, Error> {
+        client.post_with_params("/invoiceitems", params)
+    }
+
+    /// Retrieves the details of an invoice item.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_invoiceitem.
+    pub fn retrieve(client: &Client, invoice_item_id: &str) -> Result<InvoiceItem, Error> {
+        client.get(&format!("/invoiceitems/{}", invoice_item_id))
+    }
+
+    /// Updates an invoice item's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_invoiceitem.
+    pub fn update(
+        client: &Client,
+        invoice_item_id: &str,
+        params: InvoiceItemUpdateParams,
+    ) -> Result<InvoiceItem, Error> {
+        client.post_with_params(&format!("/invoiceitems/{}", invoice_item_id), params)
+    }
+
+    /// Deletes an invoice item.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_invoiceitem.
+    pub fn delete(client: &Client, invoice_item_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/invoiceitems/{}", invoice_item_id))
+    }
+
+    /// Lists all invoice items.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_invoiceitems.
+    pub fn list(client: &Client, params: InvoiceItemListParams) -> Result<List<InvoiceItem>, Error> {
+        client.get_with_params("/invoiceitems", params)
+    }
 }
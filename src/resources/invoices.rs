@@ -1,64 +1,124 @@
 use error::Error;
 use client::Client;
 use params::{List, Metadata, RangeQuery, Timestamp};
-use resources::{Currency, Discount, Plan};
+use resources::{Currency, Deleted, Discount, Plan};
 
-/// The set of parameters that can be used when creating or updating an invoice.
+/// The set of parameters that can be used when creating an invoice.
 ///
-/// For more details see https://stripe.com/docs/api#create_invoice, https://stripe.com/docs/api#update_invoice.
+/// For more details see https://stripe.com/docs/api#create_invoice.
 #[derive(Default, Serialize)]
-pub struct InvoiceParams<'a> {
+pub struct InvoiceCreateParams<'a> {
+    pub customer: &'a str, // this is a required param
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_fee: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer: Option<&'a str>,
+    pub auto_advance: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing: Option<InvoiceBilling>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_source: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub statement_descriptor: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subscription: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tax_percent: Option<f64>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub closed: Option<bool>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub forgiven: Option<bool>,
 }
 
+/// The set of parameters that can be used when updating an invoice.
+///
+/// For more details see https://stripe.com/docs/api#update_invoice.
 #[derive(Default, Serialize)]
-pub struct InvoiceLineItemParams<'a> {
+pub struct InvoiceUpdateParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub amount: Option<i64>,
+    pub application_fee: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub currency: Option<Currency>,
+    pub auto_advance: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub customer: Option<&'a str>,
+    pub closed: Option<bool>, // NOTE: only for API versions before 2018-11-08, use `auto_advance` instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub days_until_due: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_source: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub discountable: Option<bool>,
+    pub due_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forgiven: Option<bool>, // NOTE: only for API versions before 2018-11-08, use `mark_uncollectible` instead
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tax_percent: Option<f64>,
+}
+
+/// The set of parameters that can be used when paying an invoice.
+///
+/// For more details see https://stripe.com/docs/api#pay_invoice.
+#[derive(Default, Serialize)]
+pub struct InvoicePayParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub invoice: Option<&'a str>,
+    pub forgive: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub metadata: Option<bool>,
+    pub paid_out_of_band: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub subscription: Option<bool>,
+    pub source: Option<&'a str>,
 }
 
-/*
-#[derive(Serialize)]
-pub struct InvoiceListLinesParams {
-    #[serde(skip_serializing_if = "Option::is_none")] pub limit: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub ending_before: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")] pub starting_after: Option<String>,
+/// The set of parameters that can be used when finalizing a draft invoice.
+///
+/// For more details see https://stripe.com/docs/api#finalize_invoice.
+#[derive(Default, Serialize)]
+pub struct InvoiceFinalizeParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_advance: Option<bool>,
+}
 
-    ..
+/// An enum representing the possible values of an `Invoice`'s `billing` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceBilling {
+    ChargeAutomatically,
+    SendInvoice,
 }
-*/
 
-#[derive(Debug, Default, Serialize)]
+/// An enum representing the possible values of an `Invoice`'s `status` field.
+///
+/// For more details see https://stripe.com/docs/billing/invoices/workflow#workflow-overview.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvoiceStatus {
+    Draft,
+    Open,
+    Paid,
+    Uncollectible,
+    Void,
+}
+
+/// The set of parameters that can be used when listing an invoice's line items.
+///
+/// For more details see https://stripe.com/docs/api#invoice_lines.
+#[derive(Default, Serialize)]
+pub struct InvoiceListLinesParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Default, Serialize)]
 pub struct InvoiceUpcomingParams<'a> {
     pub customer: &'a str,   // this is a required param
     #[serde(skip_serializing_if = "Option::is_none")] pub coupon: Option<&'a str>,
@@ -70,7 +130,7 @@ pub struct InvoiceUpcomingParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")] pub subscription_trial_end: Option<Timestamp>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 pub struct SubscriptionItemParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<&'a str>,
@@ -85,7 +145,7 @@ pub struct SubscriptionItemParams<'a> {
 }
 
 /// Period is a structure representing a start and end dates.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Period {
     pub start: Timestamp,
     pub end: Timestamp,
@@ -122,28 +182,38 @@ pub struct InvoiceLineItem {
 pub struct Invoice {
     pub id: Option<String>, // id field is not present when retrieving upcoming invoices
     pub amount_due: u64,
+    pub amount_paid: Option<u64>,
+    pub amount_remaining: Option<u64>,
     pub application_fee: Option<u64>,
     pub attempt_count: u64,
     pub attempted: bool,
+    pub auto_advance: Option<bool>,
+    pub billing: Option<InvoiceBilling>,
     pub charge: Option<String>,
-    pub closed: bool,
+    pub closed: Option<bool>, // NOTE: replaced by `status` in newer API versions
     pub currency: Currency,
+    pub created: Option<Timestamp>,
     pub customer: String,
-    pub date: Timestamp,
+    pub date: Option<Timestamp>, // NOTE: replaced by `created` in newer API versions
     pub description: Option<String>,
     pub discount: Option<Discount>,
+    pub due_date: Option<Timestamp>,
     pub ending_balance: Option<i64>,
-    pub forgiven: bool,
+    pub forgiven: Option<bool>, // NOTE: replaced by `status` in newer API versions
+    pub hosted_invoice_url: Option<String>,
+    pub invoice_pdf: Option<String>,
     pub lines: List<InvoiceLineItem>,
     pub livemode: bool,
     pub metadata: Metadata,
     pub next_payment_attempt: Option<Timestamp>,
+    pub number: Option<String>,
     pub paid: bool,
     pub period_end: Timestamp,
     pub period_start: Timestamp,
     pub receipt_number: Option<String>,
     pub starting_balance: i64,
     pub statment_descriptor: Option<String>,
+    pub status: Option<InvoiceStatus>,
     pub subscription: Option<String>,
     pub subscription_proration_date: Option<Timestamp>,
     pub subtotal: i64,
@@ -173,7 +243,7 @@ impl Invoice {
     /// Creates a new invoice.
     ///
     /// For more details see https://stripe.com/docs/api#create_invoice.
-    pub fn create(client: &Client, params: InvoiceParams) -> Result<Invoice, Error> {
+    pub fn create(client: &Client, params: InvoiceCreateParams) -> Result<Invoice, Error> {
         client.post_with_params("/invoices", params)
     }
 
@@ -184,10 +254,35 @@ impl Invoice {
         client.get(&format!("/invoices/{}", invoice_id))
     }
 
-    // TODO: Implement InvoiceListLinesParams
-    // pub fn get_lines(client: &Client, invoice_id: &str, params: InvoiceListLinesParams) -> Result<List<InvoiceLineItem>, Error> {
-    //     client.get_with_params(&format!("/invoices/{}/lines", invoice_id))
-    // }
+    /// Retrieves a page of an invoice's line items.
+    ///
+    /// For more details see https://stripe.com/docs/api#invoice_lines.
+    pub fn lines(
+        client: &Client,
+        invoice_id: &str,
+        params: InvoiceListLinesParams,
+    ) -> Result<List<InvoiceLineItem>, Error> {
+        client.get_with_params(&format!("/invoices/{}/lines", invoice_id), params)
+    }
+
+    /// Retrieves all of an invoice's line items, fetching every page after the one selected by `params`.
+    ///
+    /// For more details see https://stripe.com/docs/api#invoice_lines.
+    pub fn all_lines(
+        client: &Client,
+        invoice_id: &str,
+        params: InvoiceListLinesParams,
+    ) -> Result<Vec<InvoiceLineItem>, Error> {
+        let limit = params.limit;
+        Invoice::lines(client, invoice_id, params)?.paginate(|starting_after| {
+            let params = InvoiceListLinesParams {
+                ending_before: None,
+                limit: limit,
+                starting_after: Some(starting_after),
+            };
+            Invoice::lines(client, invoice_id, params)
+        })
+    }
 
     /// Retrieves the details of an upcoming invoice_id
     ///
@@ -196,20 +291,96 @@ impl Invoice {
         client.get_with_params("/invoices/upcoming", params)
     }
 
-    /// Pays an invoice.
+    /// Retrieves a page of the upcoming invoice's line items.
+    ///
+    /// For more details see https://stripe.com/docs/api#upcoming_invoice_lines.
+    pub fn upcoming_lines(
+        client: &Client,
+        upcoming: InvoiceUpcomingParams,
+        params: InvoiceListLinesParams,
+    ) -> Result<List<InvoiceLineItem>, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            #[serde(flatten)]
+            upcoming: InvoiceUpcomingParams<'a>,
+            #[serde(flatten)]
+            lines: InvoiceListLinesParams<'a>,
+        }
+
+        client.get_with_params(
+            "/invoices/upcoming/lines",
+            Params { upcoming: upcoming, lines: params },
+        )
+    }
+
+    /// Retrieves all of the upcoming invoice's line items, fetching every page after the one selected by `params`.
+    ///
+    /// For more details see https://stripe.com/docs/api#upcoming_invoice_lines.
+    pub fn all_upcoming_lines(
+        client: &Client,
+        upcoming: InvoiceUpcomingParams,
+        params: InvoiceListLinesParams,
+    ) -> Result<Vec<InvoiceLineItem>, Error> {
+        let limit = params.limit;
+        Invoice::upcoming_lines(client, upcoming.clone(), params)?.paginate(|starting_after| {
+            let params = InvoiceListLinesParams {
+                ending_before: None,
+                limit: limit,
+                starting_after: Some(starting_after),
+            };
+            Invoice::upcoming_lines(client, upcoming.clone(), params)
+        })
+    }
+
+    /// Pays an invoice, optionally with a specific source or by forgiving the remaining amount.
     ///
     /// For more details see https://stripe.com/docs/api#pay_invoice.
-    pub fn pay(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
-        client.post(&format!("/invoices/{}/pay", invoice_id))
+    pub fn pay(client: &Client, invoice_id: &str, params: InvoicePayParams) -> Result<Invoice, Error> {
+        client.post_with_params(&format!("/invoices/{}/pay", invoice_id), params)
     }
 
     /// Updates an invoice.
     ///
     /// For more details see https://stripe.com/docs/api#update_invoice.
-    pub fn update(client: &Client, invoice_id: &str, params: InvoiceParams) -> Result<Invoice, Error> {
+    pub fn update(client: &Client, invoice_id: &str, params: InvoiceUpdateParams) -> Result<Invoice, Error> {
         client.post_with_params(&format!("/invoices/{}", invoice_id), params)
     }
 
+    /// Finalizes a draft invoice so that it can be paid.
+    ///
+    /// For more details see https://stripe.com/docs/api#finalize_invoice.
+    pub fn finalize(client: &Client, invoice_id: &str, params: InvoiceFinalizeParams) -> Result<Invoice, Error> {
+        client.post_with_params(&format!("/invoices/{}/finalize", invoice_id), params)
+    }
+
+    /// Voids a finalized invoice.
+    ///
+    /// For more details see https://stripe.com/docs/api#void_invoice.
+    pub fn void(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        client.post(&format!("/invoices/{}/void", invoice_id))
+    }
+
+    /// Marks a finalized invoice as uncollectible.
+    ///
+    /// For more details see https://stripe.com/docs/api#mark_uncollectible_invoice.
+    pub fn mark_uncollectible(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        client.post(&format!("/invoices/{}/mark_uncollectible", invoice_id))
+    }
+
+    /// Sends an invoice to the customer for manual payment.
+    ///
+    /// For more details see https://stripe.com/docs/api#send_invoice.
+    pub fn send(client: &Client, invoice_id: &str) -> Result<Invoice, Error> {
+        client.post(&format!("/invoices/{}/send", invoice_id))
+    }
+
+    /// Deletes a draft invoice.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_invoice.
+    pub fn delete(client: &Client, invoice_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/invoices/{}", invoice_id))
+    }
+
     /// Lists all invoices.
     ///
     /// For more details see https://stripe.com/docs/api#list_invoices.
@@ -217,12 +388,3 @@ impl Invoice {
         client.get_with_params("/invoices", params)
     }
 }
-
-impl InvoiceLineItem {
-    /// Creates an invoice line item.
-    ///
-    /// For more details see https://stripe.com/docs/api#invoice_line_item_object
-    pub fn create(client: &Client, params: InvoiceLineItemParams) -> Result<InvoiceLineItem, Error> {
-        client.post_with_params("/invoiceitems", Some(params))
-    }
-}
@@ -0,0 +1,31 @@
+use error::Error;
+use client::Client;
+use params::Timestamp;
+
+/// The set of parameters that can be used when creating a login link.
+///
+/// For more details see https://stripe.com/docs/api#create_login_link.
+#[derive(Default, Serialize)]
+pub struct LoginLinkParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_url: Option<&'a str>,
+}
+
+/// The resource representing a Stripe login link for an Express account's dashboard.
+///
+/// For more details see https://stripe.com/docs/api#login_link_object.
+#[derive(Debug, Deserialize)]
+pub struct LoginLink {
+    pub object: String,
+    pub created: Timestamp,
+    pub url: String,
+}
+
+impl LoginLink {
+    /// Creates a single-use login link for an Express account to access their Stripe dashboard.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_login_link.
+    pub fn create(client: &Client, account_id: &str, params: LoginLinkParams) -> Result<LoginLink, Error> {
+        client.post_with_params(&format!("/accounts/{}/login_links", account_id), params)
+    }
+}
@@ -1,4 +1,5 @@
 mod account;
+mod account_link;
 mod address;
 mod application_fee;
 mod application_refund;
@@ -13,11 +14,15 @@ mod deleted;
 mod discount;
 mod dispute;
 mod event;
+mod external_account;
 mod file;
 mod invoices;
 mod invoice_item;
+mod login_link;
+mod oauth;
 mod order;
 mod order_return;
+mod payment_intent;
 mod payout;
 mod plan;
 mod product;
@@ -27,10 +32,15 @@ mod scheduled_query;
 mod sku;
 mod source;
 mod subscription;
+mod subscription_item;
+mod three_d_secure;
+mod token;
 mod transaction;
 mod transfer;
+mod usage_record;
 
 pub use resources::account::*;
+pub use resources::account_link::*;
 pub use resources::address::*;
 pub use resources::application_fee::*;
 pub use resources::application_refund::*;
@@ -45,11 +55,15 @@ pub use resources::deleted::*;
 pub use resources::discount::*;
 pub use resources::dispute::*;
 pub use resources::event::*;
+pub use resources::external_account::*;
 pub use resources::file::*;
 pub use resources::invoices::*;
 pub use resources::invoice_item::*;
+pub use resources::login_link::*;
+pub use resources::oauth::*;
 pub use resources::order::*;
 pub use resources::order_return::*;
+pub use resources::payment_intent::*;
 pub use resources::payout::*;
 pub use resources::plan::*;
 pub use resources::product::*;
@@ -59,5 +73,9 @@ pub use resources::scheduled_query::*;
 pub use resources::sku::*;
 pub use resources::source::*;
 pub use resources::subscription::*;
+pub use resources::subscription_item::*;
+pub use resources::three_d_secure::*;
+pub use resources::token::*;
 pub use resources::transaction::*;
 pub use resources::transfer::*;
+pub use resources::usage_record::*;
@@ -0,0 +1,144 @@
+use error::Error;
+use client::Client;
+
+string_enum! {
+    /// An enum representing the possible values of the `scope` of an OAuth connection.
+    pub enum OAuthScope {
+        Express => "express", // NOTE: returned when connecting express accounts
+        ReadOnly => "read_only",
+        ReadWrite => "read_write",
+    }
+}
+
+/// The information used to prefill the account application form of a user that is being connected.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#get-authorize-request.
+#[derive(Clone, Debug, Default)]
+pub struct OAuthStripeUserParams<'a> {
+    pub business_name: Option<&'a str>,
+    pub country: Option<&'a str>,
+    pub email: Option<&'a str>,
+    pub first_name: Option<&'a str>,
+    pub last_name: Option<&'a str>,
+    pub phone_number: Option<&'a str>,
+    pub url: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when building an OAuth authorize url.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#get-authorize-request.
+#[derive(Clone, Debug, Default)]
+pub struct OAuthAuthorizeParams<'a> {
+    pub client_id: &'a str,
+    pub always_prompt: Option<bool>,
+    pub redirect_uri: Option<&'a str>,
+    pub scope: Option<OAuthScope>,
+    pub state: Option<&'a str>, // NOTE: should be a CSRF token unique to the user's session
+    pub stripe_landing: Option<&'a str>, // (login, register)
+    pub stripe_user: Option<OAuthStripeUserParams<'a>>,
+    pub suggested_capabilities: Vec<&'a str>,
+}
+
+/// The result of exchanging an authorization code for access to a connected account.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#post-token-response.
+#[derive(Debug, Deserialize)]
+pub struct OAuthToken {
+    pub access_token: Option<String>,
+    pub livemode: bool,
+    pub refresh_token: Option<String>,
+    pub scope: OAuthScope,
+    pub stripe_publishable_key: Option<String>,
+    pub stripe_user_id: String,
+    pub token_type: Option<String>,
+}
+
+/// The result of deauthorizing a connected account.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference#post-deauthorize-response.
+#[derive(Debug, Deserialize)]
+pub struct OAuthDeauthorization {
+    pub stripe_user_id: String,
+}
+
+/// Helpers for the Stripe Connect OAuth flow.
+///
+/// For more details see https://stripe.com/docs/connect/oauth-reference.
+pub struct OAuth {}
+
+impl OAuth {
+    /// Builds the url that a user should be redirected to in order to connect their account.
+    ///
+    /// For more details see https://stripe.com/docs/connect/oauth-reference#get-authorize.
+    pub fn authorize_url(client: &Client, params: OAuthAuthorizeParams) -> String {
+        let mut url = client.connect_url("/oauth/authorize");
+        {
+            let mut query = url.query_pairs_mut();
+            query.append_pair("response_type", "code");
+            query.append_pair("client_id", params.client_id);
+            if let Some(scope) = params.scope {
+                query.append_pair("scope", scope.as_str());
+            }
+            if let Some(redirect_uri) = params.redirect_uri {
+                query.append_pair("redirect_uri", redirect_uri);
+            }
+            if let Some(state) = params.state {
+                query.append_pair("state", state);
+            }
+            if let Some(stripe_landing) = params.stripe_landing {
+                query.append_pair("stripe_landing", stripe_landing);
+            }
+            if let Some(always_prompt) = params.always_prompt {
+                query.append_pair("always_prompt", if always_prompt { "true" } else { "false" });
+            }
+            for capability in &params.suggested_capabilities {
+                query.append_pair("suggested_capabilities[]", capability);
+            }
+            if let Some(ref user) = params.stripe_user {
+                let fields = [
+                    ("business_name", user.business_name),
+                    ("country", user.country),
+                    ("email", user.email),
+                    ("first_name", user.first_name),
+                    ("last_name", user.last_name),
+                    ("phone_number", user.phone_number),
+                    ("url", user.url),
+                ];
+                for &(name, value) in fields.iter() {
+                    if let Some(value) = value {
+                        query.append_pair(&format!("stripe_user[{}]", name), value);
+                    }
+                }
+            }
+        }
+        url.into_string()
+    }
+
+    /// Exchanges the authorization code returned to the `redirect_uri` for access to the connected account.
+    ///
+    /// For more details see https://stripe.com/docs/connect/oauth-reference#post-token.
+    pub fn token(client: &Client, code: &str) -> Result<OAuthToken, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            grant_type: &'static str,
+            code: &'a str,
+        }
+
+        let params = Params { grant_type: "authorization_code", code: code };
+        client.connect_post_with_params("/oauth/token", params)
+    }
+
+    /// Revokes the access of the platform to a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/connect/oauth-reference#post-deauthorize.
+    pub fn deauthorize(client: &Client, client_id: &str, stripe_user_id: &str) -> Result<OAuthDeauthorization, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            client_id: &'a str,
+            stripe_user_id: &'a str,
+        }
+
+        let params = Params { client_id: client_id, stripe_user_id: stripe_user_id };
+        client.connect_post_with_params("/oauth/deauthorize", params)
+    }
+}
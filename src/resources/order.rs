@@ -1,6 +1,28 @@
-use params::{List, Metadata, Timestamp};
-use resources::{Currency, ShippingDetails};
-use serde_json as json;
+use error::Error;
+use client::Client;
+use params::{List, Metadata, RangeQuery, Timestamp};
+use resources::{Currency, CustomerSourceParam, OrderReturn, ShippingDetails};
+
+/// An enum representing the possible values of an `Order`'s `status` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    Canceled,
+    Created,
+    Fulfilled,
+    Paid,
+    Returned,
+}
+
+/// An enum representing the possible values of an `OrderItem`'s `type` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderItemType {
+    Discount,
+    Shipping,
+    Sku,
+    Tax,
+}
 
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct StatusTransitions {
@@ -14,6 +36,168 @@ pub struct StatusTransitions {
     pub returned: Option<Timestamp>,
 }
 
+/// An enum representing the possible values of a `DeliveryEstimate`'s `type` field.
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryEstimateType {
+    Exact,
+    Range,
+}
+
+/// The estimated delivery date of a shipping method.
+#[derive(Debug, Deserialize)]
+pub struct DeliveryEstimate {
+    pub date: Option<String>, // NOTE: only present for exact estimates
+    pub earliest: Option<String>, // NOTE: only present for range estimates
+    pub latest: Option<String>, // NOTE: only present for range estimates
+    #[serde(rename = "type")]
+    pub estimate_type: DeliveryEstimateType,
+}
+
+/// A shipping method which can be selected for an order.
+///
+/// For more details see https://stripe.com/docs/api#order_object-shipping_methods.
+#[derive(Debug, Deserialize)]
+pub struct ShippingMethod {
+    pub id: String,
+    pub amount: u64,
+    pub currency: Currency,
+    pub delivery_estimate: Option<DeliveryEstimate>,
+    pub description: String,
+}
+
+/// The set of parameters that can be used to describe an item of an order or an order return.
+///
+/// For more details see https://stripe.com/docs/api#create_order-items.
+#[derive(Default, Serialize)]
+pub struct OrderItemParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<&'a str>, // eg. the id of a SKU
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<OrderItemType>,
+}
+
+/// The set of parameters that can be used when creating an order.
+///
+/// For more details see https://stripe.com/docs/api#create_order.
+#[derive(Default, Serialize)]
+pub struct OrderParams<'a> {
+    pub currency: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<OrderItemParams<'a>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<ShippingDetails>,
+}
+
+/// The set of parameters that can be used to record how an order was shipped.
+#[derive(Default, Serialize)]
+pub struct OrderShippingParams<'a> {
+    pub carrier: &'a str,
+    pub tracking_number: &'a str,
+}
+
+/// The set of parameters that can be used when updating an order.
+///
+/// For more details see https://stripe.com/docs/api#update_order.
+#[derive(Default, Serialize)]
+pub struct OrderUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coupon: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_shipping_method: Option<&'a str>, // the id of one of the order's `shipping_methods`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<OrderShippingParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+}
+
+/// The set of parameters that can be used when paying an order.
+///
+/// Either a `customer` or a `source` is required.
+///
+/// For more details see https://stripe.com/docs/api#pay_order.
+#[derive(Default, Serialize)]
+pub struct OrderPayParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<CustomerSourceParam<'a>>,
+}
+
+/// The set of parameters that can be used when returning an order.
+///
+/// If `items` is `None`, all of the order's items are returned.
+///
+/// For more details see https://stripe.com/docs/api#return_order.
+#[derive(Default, Serialize)]
+pub struct OrderReturnParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<OrderItemParams<'a>>>,
+}
+
+/// A set of filters on the dates at which orders changed status.
+#[derive(Default, Serialize)]
+pub struct StatusTransitionsFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub canceled: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fulfilled: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paid: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub returned: Option<RangeQuery<Timestamp>>,
+}
+
+/// The set of parameters that can be used when listing orders.
+///
+/// For more details see https://stripe.com/docs/api#list_orders.
+#[derive(Default, Serialize)]
+pub struct OrderListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<OrderStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status_transitions: Option<StatusTransitionsFilter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upstream_ids: Option<Vec<&'a str>>,
+}
+
 /// The resource representing a Stripe order item.
 ///
 /// For more details see https://stripe.com/docs/api#order_item_object.
@@ -26,7 +210,7 @@ pub struct OrderItem {
     pub parent: Option<String>,
     pub quantity: Option<u64>,
     #[serde(rename = "type")]
-    pub item_type: String,
+    pub item_type: OrderItemType,
 }
 
 /// The resource representing a Stripe order.
@@ -37,24 +221,68 @@ pub struct Order {
     pub id: String,
     pub object: String,
     pub amount: u64,
-    pub amount_returned: u64,
-    pub application: String,
-    pub application_fee: u64,
+    pub amount_returned: Option<u64>,
+    pub application: Option<String>,
+    pub application_fee: Option<u64>,
     pub charge: Option<String>,
     pub created: Timestamp,
     pub currency: Currency,
-    pub customer: String,
-    pub email: String,
-    pub external_coupon_code: String,
-    pub items: List<OrderItem>,
+    pub customer: Option<String>,
+    pub email: Option<String>,
+    pub external_coupon_code: Option<String>,
+    pub items: Vec<OrderItem>,
     pub livemode: bool,
     pub metadata: Metadata,
-    pub returns: List<json::Value>,
+    pub returns: List<OrderReturn>,
     pub selected_shipping_method: Option<String>,
     pub shipping: Option<ShippingDetails>,
-    pub shipping_methods: List<json::Value>,
-    pub status: String, // (created, paid, canceled, fulfilled, returned)
+    pub shipping_methods: Option<Vec<ShippingMethod>>,
+    pub status: OrderStatus,
     pub status_transitions: StatusTransitions,
-    pub udpated: Timestamp,
+    pub updated: Timestamp,
     pub upstream_id: Option<String>,
 }
+
+impl Order {
+    /// Creates a new order.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_order.
+    pub fn create(client: &Client, params: OrderParams) -> Result<Order, Error> {
+        client.post_with_params("/orders", params)
+    }
+
+    /// Retrieves the details of an order.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_order.
+    pub fn retrieve(client: &Client, order_id: &str) -> Result<Order, Error> {
+        client.get(&format!("/orders/{}", order_id))
+    }
+
+    /// Updates an order's properties, such as its selected shipping method or status.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_order.
+    pub fn update(client: &Client, order_id: &str, params: OrderUpdateParams) -> Result<Order, Error> {
+        client.post_with_params(&format!("/orders/{}", order_id), params)
+    }
+
+    /// Pays an order.
+    ///
+    /// For more details see https://stripe.com/docs/api#pay_order.
+    pub fn pay(client: &Client, order_id: &str, params: OrderPayParams) -> Result<Order, Error> {
+        client.post_with_params(&format!("/orders/{}/pay", order_id), params)
+    }
+
+    /// Lists all orders.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_orders.
+    pub fn list(client: &Client, params: OrderListParams) -> Result<List<Order>, Error> {
+        client.get_with_params("/orders", params)
+    }
+
+    /// Returns all or some of the items of a paid order.
+    ///
+    /// For more details see https://stripe.com/docs/api#return_order.
+    pub fn return_order(client: &Client, order_id: &str, params: OrderReturnParams) -> Result<OrderReturn, Error> {
+        client.post_with_params(&format!("/orders/{}/returns", order_id), params)
+    }
+}
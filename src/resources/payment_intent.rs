@@ -0,0 +1,312 @@
+use error::{Error, RequestError};
+use client::Client;
+use params::{List, Metadata, RangeQuery, Timestamp};
+use resources::{Charge, Currency, ShippingDetails};
+use serde_json as json;
+
+string_enum! {
+    /// An enum representing the possible values of a `PaymentIntent`'s `status` field.
+    pub enum PaymentIntentStatus {
+        Canceled => "canceled",
+        Processing => "processing",
+        RequiresAction => "requires_action",
+        RequiresCapture => "requires_capture",
+        RequiresConfirmation => "requires_confirmation",
+        RequiresPaymentMethod => "requires_payment_method",
+        RequiresSource => "requires_source", // NOTE: replaced by `requires_payment_method` in API version 2019-02-11
+        RequiresSourceAction => "requires_source_action", // NOTE: replaced by `requires_action` in API version 2019-02-11
+        Succeeded => "succeeded",
+    }
+}
+
+/// An enum representing the possible values of a `PaymentIntent`'s `capture_method` field.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureMethod {
+    Automatic,
+    Manual,
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `PaymentIntent`'s `confirmation_method` field.
+    pub enum ConfirmationMethod {
+        Automatic => "automatic",
+        Manual => "manual",
+        Publishable => "publishable", // NOTE: replaced by `automatic` in API version 2019-02-11
+        Secret => "secret", // NOTE: replaced by `manual` in API version 2019-02-11
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `PaymentIntent`'s `cancellation_reason` field.
+    pub enum PaymentIntentCancellationReason {
+        Abandoned => "abandoned",
+        Automatic => "automatic",
+        Duplicate => "duplicate",
+        FailedInvoice => "failed_invoice",
+        Fraudulent => "fraudulent",
+        RequestedByCustomer => "requested_by_customer",
+        VoidInvoice => "void_invoice",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `PaymentIntentNextAction`'s `type` field.
+    pub enum PaymentIntentNextActionType {
+        RedirectToUrl => "redirect_to_url",
+        UseStripeSdk => "use_stripe_sdk",
+    }
+}
+
+/// The url a customer must be redirected to in order to authenticate a payment.
+#[derive(Debug, Deserialize)]
+pub struct PaymentIntentRedirectToUrl {
+    pub return_url: Option<String>,
+    pub url: String,
+}
+
+/// The action the customer must take for a `RequiresAction` payment intent to proceed.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/object#payment_intent_object-next_action.
+#[derive(Debug, Deserialize)]
+pub struct PaymentIntentNextAction {
+    #[serde(rename = "type")]
+    pub action_type: PaymentIntentNextActionType,
+    pub redirect_to_url: Option<PaymentIntentRedirectToUrl>,
+    pub use_stripe_sdk: Option<json::Value>, // NOTE: opaque, to be handled by Stripe.js
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PaymentIntentTransferData {
+    pub destination: String, // the id of a connected account
+}
+
+/// The set of parameters that can be used when creating a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/create.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentCreateParams<'a> {
+    pub amount: u64,
+    pub currency: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_method: Option<CaptureMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirmation_method: Option<ConfirmationMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_types: Option<Vec<&'a str>>, // NOTE: if None, Stripe assumes ["card"]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>, // NOTE: only used when `confirm` is true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<ShippingDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_data: Option<PaymentIntentTransferData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when updating a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/update.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<ShippingDetails>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when confirming a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/confirm.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentConfirmParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt_email: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_url: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<ShippingDetails>,
+}
+
+/// The set of parameters that can be used when capturing a payment intent.
+///
+/// If `amount_to_capture` is `None`, the full `amount_capturable` is captured.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/capture.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentCaptureParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount_to_capture: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_fee_amount: Option<u64>,
+}
+
+/// The set of parameters that can be used when canceling a payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/cancel.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentCancelParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cancellation_reason: Option<PaymentIntentCancellationReason>,
+}
+
+/// The set of parameters that can be used when listing payment intents.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/list.
+#[derive(Default, Serialize)]
+pub struct PaymentIntentListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+/// The resource representing a Stripe payment intent.
+///
+/// For more details see https://stripe.com/docs/api/payment_intents/object.
+#[derive(Debug, Deserialize)]
+pub struct PaymentIntent {
+    pub id: String,
+    pub amount: u64,
+    pub amount_capturable: u64,
+    pub amount_received: u64,
+    pub application: Option<String>,
+    pub application_fee_amount: Option<u64>,
+    pub canceled_at: Option<Timestamp>,
+    pub cancellation_reason: Option<PaymentIntentCancellationReason>,
+    pub capture_method: CaptureMethod,
+    pub charges: List<Charge>,
+    pub client_secret: Option<String>,
+    pub confirmation_method: ConfirmationMethod,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub customer: Option<String>,
+    pub description: Option<String>,
+    pub last_payment_error: Option<RequestError>,
+    pub livemode: bool,
+    pub metadata: Metadata,
+    #[serde(alias = "next_source_action")]
+    pub next_action: Option<PaymentIntentNextAction>,
+    pub on_behalf_of: Option<String>,
+    pub payment_method: Option<String>,
+    #[serde(alias = "allowed_source_types")]
+    pub payment_method_types: Vec<String>,
+    pub receipt_email: Option<String>,
+    pub review: Option<String>,
+    pub shipping: Option<ShippingDetails>,
+    pub statement_descriptor: Option<String>,
+    pub status: PaymentIntentStatus,
+    pub transfer_data: Option<PaymentIntentTransferData>,
+    pub transfer_group: Option<String>,
+}
+
+impl PaymentIntent {
+    /// Creates a new payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/create.
+    pub fn create(client: &Client, params: PaymentIntentCreateParams) -> Result<PaymentIntent, Error> {
+        client.post_with_params("/payment_intents", params)
+    }
+
+    /// Retrieves the details of a payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/retrieve.
+    pub fn retrieve(client: &Client, payment_intent_id: &str) -> Result<PaymentIntent, Error> {
+        client.get(&format!("/payment_intents/{}", payment_intent_id))
+    }
+
+    /// Updates a payment intent's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/update.
+    pub fn update(
+        client: &Client,
+        payment_intent_id: &str,
+        params: PaymentIntentUpdateParams,
+    ) -> Result<PaymentIntent, Error> {
+        client.post_with_params(&format!("/payment_intents/{}", payment_intent_id), params)
+    }
+
+    /// Confirms that the customer intends to pay, attempting the payment.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/confirm.
+    pub fn confirm(
+        client: &Client,
+        payment_intent_id: &str,
+        params: PaymentIntentConfirmParams,
+    ) -> Result<PaymentIntent, Error> {
+        client.post_with_params(&format!("/payment_intents/{}/confirm", payment_intent_id), params)
+    }
+
+    /// Captures the funds of a payment intent created with `CaptureMethod::Manual`.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/capture.
+    pub fn capture(
+        client: &Client,
+        payment_intent_id: &str,
+        params: PaymentIntentCaptureParams,
+    ) -> Result<PaymentIntent, Error> {
+        client.post_with_params(&format!("/payment_intents/{}/capture", payment_intent_id), params)
+    }
+
+    /// Cancels a payment intent.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/cancel.
+    pub fn cancel(
+        client: &Client,
+        payment_intent_id: &str,
+        params: PaymentIntentCancelParams,
+    ) -> Result<PaymentIntent, Error> {
+        client.post_with_params(&format!("/payment_intents/{}/cancel", payment_intent_id), params)
+    }
+
+    /// Lists all payment intents.
+    ///
+    /// For more details see https://stripe.com/docs/api/payment_intents/list.
+    pub fn list(client: &Client, params: PaymentIntentListParams) -> Result<List<PaymentIntent>, Error> {
+        client.get_with_params("/payment_intents", params)
+    }
+}
@@ -1,6 +1,96 @@
-use params::{Metadata, Timestamp};
+use error::Error;
+use client::Client;
+use params::{List, Metadata, RangeQuery, Timestamp};
 use resources::Currency;
 
+string_enum! {
+    /// An enum representing the possible values of a `Payout`'s `method` field.
+    pub enum PayoutMethod {
+        Instant => "instant",
+        Standard => "standard",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `Payout`'s `source_type` field.
+    pub enum PayoutSourceType {
+        AlipayAccount => "alipay_account",
+        BankAccount => "bank_account",
+        BitcoinReceiver => "bitcoin_receiver",
+        Card => "card",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `Payout`'s `status` field.
+    pub enum PayoutStatus {
+        Canceled => "canceled",
+        Failed => "failed",
+        InTransit => "in_transit",
+        Paid => "paid",
+        Pending => "pending",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `Payout`'s `type` field.
+    pub enum PayoutType {
+        BankAccount => "bank_account",
+        Card => "card",
+    }
+}
+
+/// The set of parameters that can be used when creating a payout.
+///
+/// For more details see https://stripe.com/docs/api#create_payout.
+#[derive(Default, Serialize)]
+pub struct PayoutParams<'a> {
+    pub amount: u64,
+    pub currency: Currency,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<PayoutMethod>, // NOTE: if None, Stripe assumes standard
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<PayoutSourceType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when updating a payout.
+///
+/// For more details see https://stripe.com/docs/api#update_payout.
+#[derive(Default, Serialize)]
+pub struct PayoutUpdateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing payouts.
+///
+/// For more details see https://stripe.com/docs/api#list_payouts.
+#[derive(Default, Serialize)]
+pub struct PayoutListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub arrival_date: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<PayoutStatus>,
+}
+
 /// The resource representing a Stripe payout.
 ///
 /// For more details see https://stripe.com/docs/api#payout_object.
@@ -10,20 +100,57 @@ pub struct Payout {
     pub object: String,
     pub amount: u64,
     pub arrival_date: Timestamp,
-    pub balance_transaction: String,
+    pub balance_transaction: Option<String>,
     pub created: Timestamp,
     pub currency: Currency,
-    pub description: String,
+    pub description: Option<String>,
     pub destination: Option<String>,
     pub failure_balance_transaction: Option<String>,
     pub failure_code: Option<String>,
     pub failure_message: Option<String>,
     pub livemode: bool,
     pub metadata: Metadata,
-    pub method: String, // (standard, instant)
-    pub source_type: String, // (card, bank_account, bitcoin_receiver, alipay_account)
+    pub method: PayoutMethod,
+    pub source_type: PayoutSourceType,
     pub statement_descriptor: Option<String>,
-    pub status: String,
+    pub status: PayoutStatus,
     #[serde(rename = "type")]
-    pub payout_type: String, // (bank_account, card)
+    pub payout_type: PayoutType,
+}
+
+impl Payout {
+    /// Creates a new payout to the account's bank account or debit card.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_payout.
+    pub fn create(client: &Client, params: PayoutParams) -> Result<Payout, Error> {
+        client.post_with_params("/payouts", params)
+    }
+
+    /// Retrieves the details of a payout.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_payout.
+    pub fn retrieve(client: &Client, payout_id: &str) -> Result<Payout, Error> {
+        client.get(&format!("/payouts/{}", payout_id))
+    }
+
+    /// Updates a payout's metadata.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_payout.
+    pub fn update(client: &Client, payout_id: &str, params: PayoutUpdateParams) -> Result<Payout, Error> {
+        client.post_with_params(&format!("/payouts/{}", payout_id), params)
+    }
+
+    /// Lists all payouts.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_payouts.
+    pub fn list(client: &Client, params: PayoutListParams) -> Result<List<Payout>, Error> {
+        client.get_with_params("/payouts", params)
+    }
+
+    /// Cancels a pending payout.
+    ///
+    /// For more details see https://stripe.com/docs/api#cancel_payout.
+    pub fn cancel(client: &Client, payout_id: &str) -> Result<Payout, Error> {
+        client.post(&format!("/payouts/{}/cancel", payout_id))
+    }
 }
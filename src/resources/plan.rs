@@ -1,7 +1,154 @@
 use error::Error;
 use client::Client;
-use params::{Metadata, Timestamp};
+use params::{List, Metadata, RangeQuery, Timestamp};
 use resources::{Currency, Deleted};
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+
+/// An enum representing the possible values of a `Plan`'s `interval` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+/// An enum representing the possible values of a `Plan`'s `billing_scheme` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanBillingScheme {
+    PerUnit,
+    Tiered,
+}
+
+/// An enum representing the possible values of a `Plan`'s `tiers_mode` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanTiersMode {
+    Graduated,
+    Volume,
+}
+
+/// An enum representing the possible values of a `Plan`'s `usage_type` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanUsageType {
+    Licensed,
+    Metered,
+}
+
+/// An enum representing the possible values of a `Plan`'s `aggregate_usage` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanAggregateUsage {
+    LastDuringPeriod,
+    LastEver,
+    Max,
+    Sum,
+}
+
+/// An enum representing how the result of a `TransformUsage` division is rounded.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransformUsageRound {
+    Down,
+    Up,
+}
+
+/// Apply a transformation to the reported usage or set quantity before computing the billed price.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TransformUsage {
+    pub divide_by: u64,
+    pub round: TransformUsageRound,
+}
+
+/// The upper bound of a `PlanTierParams`, where the last tier must use `Inf`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UpTo {
+    Max(u64),
+    Inf,
+}
+
+impl Serialize for UpTo {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            UpTo::Max(max) => serializer.serialize_u64(max),
+            UpTo::Inf => serializer.serialize_str("inf"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for UpTo {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<UpTo, D::Error> {
+        struct UpToVisitor;
+
+        impl<'de> Visitor<'de> for UpToVisitor {
+            type Value = UpTo;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("an integer or \"inf\"")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<UpTo, E> {
+                Ok(UpTo::Max(value))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<UpTo, E> {
+                match value {
+                    "inf" => Ok(UpTo::Inf),
+                    _ => Err(E::invalid_value(de::Unexpected::Str(value), &self)),
+                }
+            }
+        }
+
+        deserializer.deserialize_any(UpToVisitor)
+    }
+}
+
+/// The set of parameters that can be used to define a pricing tier of a tiered plan.
+///
+/// For more details see https://stripe.com/docs/api#create_plan-tiers.
+#[derive(Debug, Serialize)]
+pub struct PlanTierParams {
+    pub up_to: UpTo,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flat_amount: Option<u64>,
+}
+
+/// A pricing tier of a tiered plan.
+///
+/// For more details see https://stripe.com/docs/api#plan_object-tiers.
+#[derive(Debug, Deserialize)]
+pub struct PlanTier {
+    pub amount: Option<u64>,
+    pub flat_amount: Option<u64>,
+    pub up_to: Option<u64>, // NOTE: None for the last tier
+}
+
+/// The set of parameters that can be used to create a product along with a plan.
+#[derive(Debug, Default, Serialize)]
+pub struct PlanProductParams<'a> {
+    pub name: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+/// Either the id of an existing product, or the params of a new product.
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum PlanProduct<'a> {
+    Id(&'a str),
+    Product(PlanProductParams<'a>),
+}
 
 /// The set of parameters that can be used when creating or updating a plan.
 ///
@@ -15,18 +162,55 @@ pub struct PlanParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub interval: Option<&'a str>, // (day, week, month, year)
+    pub interval: Option<PlanInterval>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<PlanProduct<'a>>,
 
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aggregate_usage: Option<PlanAggregateUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_scheme: Option<PlanBillingScheme>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub interval_count: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub statement_descriptor: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers: Option<Vec<PlanTierParams>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tiers_mode: Option<PlanTiersMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transform_usage: Option<TransformUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub trial_period_days: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_type: Option<PlanUsageType>,
+}
+
+/// The set of parameters that can be used when listing plans.
+///
+/// For more details see https://stripe.com/docs/api#list_plans.
+#[derive(Default, Serialize)]
+pub struct PlanListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
 }
 
 /// The resource representing a Stripe plan.
@@ -35,16 +219,24 @@ pub struct PlanParams<'a> {
 #[derive(Debug, Deserialize)]
 pub struct Plan {
     pub id: String,
-    pub amount: u64,
+    pub active: Option<bool>,
+    pub aggregate_usage: Option<PlanAggregateUsage>,
+    pub amount: Option<u64>, // NOTE: None for tiered plans
+    pub billing_scheme: Option<PlanBillingScheme>,
     pub created: Timestamp,
     pub currency: Currency,
-    pub interval: String, // (day, week, month, year)
+    pub interval: PlanInterval,
     pub interval_count: u64,
     pub livemode: bool,
     pub metadata: Metadata,
-    pub nickname: String,
+    pub nickname: Option<String>,
+    pub product: Option<String>,
     pub statement_descriptor: Option<String>,
+    pub tiers: Option<Vec<PlanTier>>,
+    pub tiers_mode: Option<PlanTiersMode>,
+    pub transform_usage: Option<TransformUsage>,
     pub trial_period_days: Option<u64>,
+    pub usage_type: Option<PlanUsageType>,
 }
 
 impl Plan {
@@ -75,4 +267,11 @@ impl Plan {
     pub fn delete(client: &Client, plan_id: &str) -> Result<Deleted, Error> {
         client.delete(&format!("/plans/{}", plan_id))
     }
+
+    /// Lists all plans.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_plans.
+    pub fn list(client: &Client, params: PlanListParams) -> Result<List<Plan>, Error> {
+        client.get_with_params("/plans", params)
+    }
 }
@@ -1,5 +1,7 @@
-use params::{List, Metadata, Timestamp};
-use resources::Sku;
+use error::Error;
+use client::Client;
+use params::{List, Metadata, RangeQuery, Timestamp};
+use resources::{Deleted, Sku};
 
 #[derive(Debug, Default, Deserialize, Serialize)]
 pub struct PackageDimensions {
@@ -9,6 +11,112 @@ pub struct PackageDimensions {
     pub width: f64,
 }
 
+/// An enum representing the possible values of a `Product`'s `type` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProductType {
+    Good,
+    Service,
+}
+
+/// The set of parameters that can be used when creating a product.
+///
+/// For more details see https://stripe.com/docs/api#create_product.
+#[derive(Default, Serialize)]
+pub struct ProductCreateParams<'a> {
+    pub name: &'a str, // this is a required param
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<ProductType>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deactivate_on: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_dimensions: Option<PackageDimensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shippable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_label: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when updating a product.
+///
+/// For more details see https://stripe.com/docs/api#update_product.
+#[derive(Default, Serialize)]
+pub struct ProductUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caption: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deactivate_on: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_dimensions: Option<PackageDimensions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shippable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit_label: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when listing products.
+///
+/// For more details see https://stripe.com/docs/api#list_products.
+#[derive(Default, Serialize)]
+pub struct ProductListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shippable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product_type: Option<ProductType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<&'a str>,
+}
+
 /// The resource representing a Stripe product.
 ///
 /// For more details see https://stripe.com/docs/api#product_object.
@@ -17,18 +125,59 @@ pub struct Product {
     pub id: String,
     pub object: String,
     pub active: Option<bool>,
-    pub attributes: Vec<String>,
+    pub attributes: Option<Vec<String>>,
     pub caption: Option<String>,
     pub created: Timestamp,
-    pub deactivate_on: Vec<String>,
+    pub deactivate_on: Option<Vec<String>>,
     pub description: Option<String>,
-    pub images: Vec<String>,
+    pub images: Option<Vec<String>>,
     pub livemode: bool,
     pub metadata: Metadata,
     pub name: String,
     pub package_dimensions: Option<PackageDimensions>,
     pub shippable: Option<bool>,
-    pub skus: List<Sku>,
+    pub skus: Option<List<Sku>>, // NOTE: only present for products of type good
+    pub statement_descriptor: Option<String>,
+    #[serde(rename = "type")]
+    pub product_type: Option<ProductType>,
+    pub unit_label: Option<String>,
     pub updated: Timestamp,
     pub url: Option<String>,
 }
+
+impl Product {
+    /// Creates a new product.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_product.
+    pub fn create(client: &Client, params: ProductCreateParams) -> Result<Product, Error> {
+        client.post_with_params("/products", params)
+    }
+
+    /// Retrieves the details of a product.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_product.
+    pub fn retrieve(client: &Client, product_id: &str) -> Result<Product, Error> {
+        client.get(&format!("/products/{}", product_id))
+    }
+
+    /// Updates a product's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_product.
+    pub fn update(client: &Client, product_id: &str, params: ProductUpdateParams) -> Result<Product, Error> {
+        client.post_with_params(&format!("/products/{}", product_id), params)
+    }
+
+    /// Deletes a product.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_product.
+    pub fn delete(client: &Client, product_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/products/{}", product_id))
+    }
+
+    /// Lists all products.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_products.
+    pub fn list(client: &Client, params: ProductListParams) -> Result<List<Product>, Error> {
+        client.get_with_params("/products", params)
+    }
+}
@@ -1,4 +1,74 @@
-use params::Timestamp;
+use error::Error;
+use client::Client;
+use params::{List, RangeQuery, Timestamp};
+
+string_enum! {
+    /// An enum representing the possible values of a `Review`'s `reason` field.
+    pub enum ReviewReason {
+        Approved => "approved",
+        Disputed => "disputed",
+        Manual => "manual",
+        Refunded => "refunded",
+        RefundedAsFraud => "refunded_as_fraud",
+        Rule => "rule",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `Review`'s `opened_reason` field.
+    pub enum ReviewOpenedReason {
+        Manual => "manual",
+        Rule => "rule",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `Review`'s `closed_reason` field.
+    pub enum ReviewClosedReason {
+        Approved => "approved",
+        Disputed => "disputed",
+        Refunded => "refunded",
+        RefundedAsFraud => "refunded_as_fraud",
+    }
+}
+
+/// The geographic location estimated from the IP address of a payment.
+///
+/// For more details see https://stripe.com/docs/api#review_object-ip_address_location.
+#[derive(Debug, Deserialize)]
+pub struct IpAddressLocation {
+    pub city: Option<String>,
+    pub country: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub region: Option<String>,
+}
+
+/// Information about the browser session in which a payment was made.
+///
+/// For more details see https://stripe.com/docs/api#review_object-session.
+#[derive(Debug, Deserialize)]
+pub struct ReviewSession {
+    pub browser: Option<String>,
+    pub device: Option<String>,
+    pub platform: Option<String>,
+    pub version: Option<String>,
+}
+
+/// The set of parameters that can be used when listing reviews.
+///
+/// For more details see https://stripe.com/docs/api#list_reviews.
+#[derive(Default, Serialize)]
+pub struct ReviewListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
 
 /// The resource representing a Stripe review of a payment.
 ///
@@ -7,9 +77,38 @@ use params::Timestamp;
 pub struct Review {
     pub id: String,
     pub object: String,
-    pub charge: String,
+    pub billing_zip: Option<String>,
+    pub charge: Option<String>,
+    pub closed_reason: Option<ReviewClosedReason>,
     pub created: Timestamp,
+    pub ip_address: Option<String>,
+    pub ip_address_location: Option<IpAddressLocation>,
     pub livemode: bool,
     pub open: bool,
-    pub reason: String,
+    pub opened_reason: Option<ReviewOpenedReason>, // NOTE: not returned by older API versions
+    pub reason: ReviewReason,
+    pub session: Option<ReviewSession>,
+}
+
+impl Review {
+    /// Retrieves the details of a review.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_review.
+    pub fn retrieve(client: &Client, review_id: &str) -> Result<Review, Error> {
+        client.get(&format!("/reviews/{}", review_id))
+    }
+
+    /// Lists the reviews that are open for the account.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_reviews.
+    pub fn list(client: &Client, params: ReviewListParams) -> Result<List<Review>, Error> {
+        client.get_with_params("/reviews", params)
+    }
+
+    /// Approves an open review, closing it and removing it from the list of reviews.
+    ///
+    /// For more details see https://stripe.com/docs/api#approve_review.
+    pub fn approve(client: &Client, review_id: &str) -> Result<Review, Error> {
+        client.post(&format!("/reviews/{}/approve", review_id))
+    }
 }
@@ -1,6 +1,38 @@
-use params::Timestamp;
+use std::io::Write;
+
+use error::Error;
+use client::Client;
+use params::{List, Timestamp};
 use resources::File;
-use serde_json as json;
+
+/// An enum representing the possible values of a `ScheduledQueryRun`'s `status` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledQueryRunStatus {
+    Canceled,
+    Completed,
+    Failed,
+    TimedOut,
+}
+
+/// The error that caused a scheduled query run to fail.
+#[derive(Debug, Deserialize)]
+pub struct ScheduledQueryRunError {
+    pub message: String,
+}
+
+/// The set of parameters that can be used when listing scheduled query runs.
+///
+/// For more details see https://stripe.com/docs/api#list_scheduled_query_runs.
+#[derive(Default, Serialize)]
+pub struct ScheduledQueryRunListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
 
 /// The resource representing a Stripe scheduled query run.
 ///
@@ -11,11 +43,104 @@ pub struct ScheduledQueryRun {
     pub object: String,
     pub created: Timestamp,
     pub data_load_time: Timestamp,
-    pub error: Option<json::Value>,
-    pub file: File,
+    pub error: Option<ScheduledQueryRunError>,
+    pub file: Option<File>, // NOTE: None unless the run completed
     pub livemode: bool,
     pub result_available_until: Timestamp,
     pub sql: String,
-    pub status: String, // (completed, canceled, failed, timed_out)
+    pub status: ScheduledQueryRunStatus,
     pub title: String,
 }
+
+impl ScheduledQueryRun {
+    /// Retrieves the details of a scheduled query run.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_scheduled_query_run.
+    pub fn retrieve(client: &Client, run_id: &str) -> Result<ScheduledQueryRun, Error> {
+        client.get(&format!("/sigma/scheduled_query_runs/{}", run_id))
+    }
+
+    /// Lists the scheduled query runs of the account.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_scheduled_query_runs.
+    pub fn list(
+        client: &Client,
+        params: ScheduledQueryRunListParams,
+    ) -> Result<List<ScheduledQueryRun>, Error> {
+        client.get_with_params("/sigma/scheduled_query_runs", params)
+    }
+
+    /// Downloads the CSV result file of the run, streaming it into `writer`.
+    ///
+    /// Returns the number of bytes written, which is 0 if the run has no result file.
+    pub fn download_result<W: Write + ?Sized>(&self, client: &Client, writer: &mut W) -> Result<u64, Error> {
+        match self.file {
+            Some(ref file) => File::download(client, &file.id, writer),
+            None => Ok(0),
+        }
+    }
+
+    /// Downloads the result file of the run and parses it into rows (starting with the header row).
+    ///
+    /// Returns an empty list if the run has no result file.
+    pub fn rows(&self, client: &Client) -> Result<Vec<Vec<String>>, Error> {
+        let mut data = Vec::new();
+        self.download_result(client, &mut data)?;
+        let data = String::from_utf8(data).map_err(|err| Error::Conversion(Box::new(err)))?;
+        Ok(ScheduledQueryRun::parse_csv(&data))
+    }
+
+    /// Parses the CSV result of a scheduled query run into rows of fields.
+    ///
+    /// Fields may be quoted, in which case they can contain commas, newlines and escaped (doubled) quotes.
+    pub fn parse_csv(data: &str) -> Vec<Vec<String>> {
+        let mut rows = Vec::new();
+        let mut row = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut started = false; // whether there is any content after the last line break
+        let mut chars = data.chars().peekable();
+        while let Some(c) = chars.next() {
+            if quoted {
+                match c {
+                    '"' if chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    }
+                    '"' => quoted = false,
+                    _ => field.push(c),
+                }
+                continue;
+            }
+            match c {
+                '"' => {
+                    quoted = true;
+                    started = true;
+                }
+                ',' => {
+                    row.push(field);
+                    field = String::new();
+                    started = true;
+                }
+                '\r' if chars.peek() == Some(&'\n') => {}
+                '\n' => {
+                    // NOTE: an empty line is a row with a single empty field
+                    row.push(field);
+                    rows.push(row);
+                    row = Vec::new();
+                    field = String::new();
+                    started = false;
+                }
+                _ => {
+                    field.push(c);
+                    started = true;
+                }
+            }
+        }
+        if started {
+            row.push(field);
+            rows.push(row);
+        }
+        rows
+    }
+}
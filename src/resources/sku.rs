@@ -1,13 +1,113 @@
-use params::{Metadata, Timestamp};
-use resources::{Currency, PackageDimensions};
-use serde_json as json;
+use error::Error;
+use client::Client;
+use params::{List, Metadata, Timestamp};
+use resources::{Currency, Deleted, PackageDimensions};
 
-#[derive(Debug, Default, Deserialize, Serialize)]
+/// An enum representing the possible values of an `Inventory`'s `type` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InventoryType {
+    Bucket,
+    Finite,
+    Infinite,
+}
+
+/// An enum representing the possible values of an `Inventory`'s `value` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InventoryValue {
+    InStock,
+    Limited,
+    OutOfStock,
+}
+
+/// The inventory of a SKU.
+///
+/// The `quantity` is only used with `finite` inventories and the `value` only with `bucket` inventories.
+///
+/// For more details see https://stripe.com/docs/api#sku_object-inventory.
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Inventory {
-    pub quantity: u64,
     #[serde(rename = "type")]
-    pub inventory_type: String,
-    pub value: Option<String>,
+    pub inventory_type: InventoryType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<InventoryValue>,
+}
+
+/// The set of parameters that can be used when creating a SKU.
+///
+/// For more details see https://stripe.com/docs/api#create_sku.
+#[derive(Default, Serialize)]
+pub struct SkuCreateParams<'a> {
+    pub product: &'a str, // this is a required param
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory: Option<Inventory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Metadata>, // eg. {"size": "Medium", "gender": "Unisex"}
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_dimensions: Option<PackageDimensions>,
+}
+
+/// The set of parameters that can be used when updating a SKU.
+///
+/// For more details see https://stripe.com/docs/api#update_sku.
+#[derive(Default, Serialize)]
+pub struct SkuUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inventory: Option<Inventory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Metadata>, // eg. {"size": "Medium", "gender": "Unisex"}
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package_dimensions: Option<PackageDimensions>,
+}
+
+/// The set of parameters that can be used when listing SKUs.
+///
+/// For more details see https://stripe.com/docs/api#list_skus.
+#[derive(Default, Serialize)]
+pub struct SkuListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ids: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_stock: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub product: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
 }
 
 /// The resource representing a Stripe Sku.
@@ -18,7 +118,7 @@ pub struct Sku {
     pub id: String,
     pub object: String,
     pub active: bool,
-    pub attributes: json::Value,
+    pub attributes: Metadata,
     pub created: Timestamp,
     pub currency: Currency,
     pub image: Option<String>,
@@ -30,3 +130,40 @@ pub struct Sku {
     pub product: String,
     pub updated: Timestamp,
 }
+
+impl Sku {
+    /// Creates a new SKU for a product.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_sku.
+    pub fn create(client: &Client, params: SkuCreateParams) -> Result<Sku, Error> {
+        client.post_with_params("/skus", params)
+    }
+
+    /// Retrieves the details of a SKU.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_sku.
+    pub fn retrieve(client: &Client, sku_id: &str) -> Result<Sku, Error> {
+        client.get(&format!("/skus/{}", sku_id))
+    }
+
+    /// Updates a SKU's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_sku.
+    pub fn update(client: &Client, sku_id: &str, params: SkuUpdateParams) -> Result<Sku, Error> {
+        client.post_with_params(&format!("/skus/{}", sku_id), params)
+    }
+
+    /// Deletes a SKU.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_sku.
+    pub fn delete(client: &Client, sku_id: &str) -> Result<Deleted, Error> {
+        client.delete(&format!("/skus/{}", sku_id))
+    }
+
+    /// Lists all SKUs.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_skus.
+    pub fn list(client: &Client, params: SkuListParams) -> Result<List<Sku>, Error> {
+        client.get_with_params("/skus", params)
+    }
+}
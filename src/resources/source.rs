@@ -1,9 +1,105 @@
 use error::Error;
 use client::Client;
-use resources::{Address, Card, Currency, BankAccount};
-use params::Metadata;
+use resources::{Address, BankAccount, Card, Currency};
+use params::{Identifiable, List, Metadata, Timestamp};
 
-#[derive(Serialize)]
+string_enum! {
+    /// An enum representing the possible values of a `Source`'s `type` field.
+    pub enum SourceKind {
+        AchCreditTransfer => "ach_credit_transfer",
+        AchDebit => "ach_debit",
+        Alipay => "alipay",
+        Bancontact => "bancontact",
+        Card => "card",
+        CardPresent => "card_present",
+        Eps => "eps",
+        Giropay => "giropay",
+        Ideal => "ideal",
+        Multibanco => "multibanco",
+        P24 => "p24",
+        SepaDebit => "sepa_debit",
+        Sofort => "sofort",
+        ThreeDSecure => "three_d_secure",
+        Wechat => "wechat",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `Source`'s `flow` field.
+    pub enum SourceFlow {
+        CodeVerification => "code_verification",
+        None => "none",
+        Receiver => "receiver",
+        Redirect => "redirect",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `Source`'s `status` field.
+    pub enum SourceStatus {
+        Canceled => "canceled",
+        Chargeable => "chargeable",
+        Consumed => "consumed",
+        Failed => "failed",
+        Pending => "pending",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `Source`'s `usage` field.
+    pub enum SourceUsage {
+        Reusable => "reusable",
+        SingleUse => "single_use",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `SourceRedirect`'s `status` field.
+    pub enum SourceRedirectStatus {
+        Failed => "failed",
+        NotRequired => "not_required",
+        Pending => "pending",
+        Succeeded => "succeeded",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `SourceRedirect`'s `failure_reason` field.
+    pub enum SourceRedirectFailureReason {
+        Declined => "declined",
+        ProcessingError => "processing_error",
+        UserAbort => "user_abort",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `SourceReceiver`'s `refund_attributes_method` field.
+    pub enum SourceRefundAttributesMethod {
+        Email => "email",
+        Manual => "manual",
+        None => "none",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `SourceReceiver`'s `refund_attributes_status` field.
+    pub enum SourceRefundAttributesStatus {
+        Available => "available",
+        Missing => "missing",
+        Requested => "requested",
+    }
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `SourceCodeVerification`'s `status` field.
+    pub enum SourceCodeVerificationStatus {
+        Failed => "failed",
+        Pending => "pending",
+        Succeeded => "succeeded",
+    }
+}
+
+#[derive(Default, Serialize)]
 pub struct OwnerParams<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub address: Option<Address>,
@@ -17,51 +113,400 @@ pub struct OwnerParams<'a> {
 
 #[derive(Serialize)]
 pub struct RedirectParams<'a> {
-    return_url: &'a str,
+    pub return_url: &'a str,
+}
+
+/// The parameters specific to creating a `three_d_secure` source from a card source.
+///
+/// For more details see https://stripe.com/docs/sources/three-d-secure.
+#[derive(Serialize)]
+pub struct ThreeDSecureParams<'a> {
+    pub card: &'a str, // the id of the card source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+}
+
+/// The parameters specific to creating a `sepa_debit` source.
+#[derive(Serialize)]
+pub struct SepaDebitParams<'a> {
+    pub iban: &'a str,
+}
+
+/// The parameters specific to creating an `ideal` source.
+#[derive(Default, Serialize)]
+pub struct IdealParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+/// The parameters specific to creating a `sofort` source.
+#[derive(Serialize)]
+pub struct SofortParams<'a> {
+    pub country: &'a str, // eg. "DE"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_language: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
 }
 
+/// The parameters specific to creating a `bancontact`, `giropay` or `alipay` source.
+#[derive(Default, Serialize)]
+pub struct RedirectSourceParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preferred_language: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when creating a source.
+///
+/// For more details see https://stripe.com/docs/api#create_source.
 #[derive(Default, Serialize)]
 pub struct SourceParams<'a> {
     #[serde(rename = "type")]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<&'static str>,
+    pub source_type: Option<SourceKind>,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub amount: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub currency: Option<Currency>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub flow: Option<&'a str>, // (redirect, receiver, code_verification, none)
+    pub customer: Option<&'a str>, // NOTE: only used when creating a source from a customer's source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flow: Option<SourceFlow>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metadata: Option<Metadata>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_source: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub owner: Option<OwnerParams<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub redirect: Option<RedirectParams<'a>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_descriptor: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub token: Option<&'a str>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub usage: Option<&'a str>, // (reusable, single-use)
+    pub usage: Option<SourceUsage>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alipay: Option<RedirectSourceParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bancontact: Option<RedirectSourceParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub giropay: Option<RedirectSourceParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ideal: Option<IdealParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sepa_debit: Option<SepaDebitParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sofort: Option<SofortParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub three_d_secure: Option<ThreeDSecureParams<'a>>,
 }
 
+/// The set of parameters that can be used when updating a source.
+///
+/// For more details see https://stripe.com/docs/api#update_source.
+#[derive(Default, Serialize)]
+pub struct SourceUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<OwnerParams<'a>>,
+}
+
+/// The set of parameters that can be used when listing the transactions of a source.
+///
+/// For more details see https://stripe.com/docs/api#source_transactions.
+#[derive(Default, Serialize)]
+pub struct SourceTransactionListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+/// Information about the owner of the payment instrument of a source.
+///
+/// For more details see https://stripe.com/docs/api#source_object-owner.
 #[derive(Debug, Deserialize)]
-#[serde(rename_all = "lowercase")]
-#[serde(tag = "object")]
-pub enum Source {
-    Card(Card),
+pub struct SourceOwner {
+    pub address: Option<Address>,
+    pub email: Option<String>,
+    pub name: Option<String>,
+    pub phone: Option<String>,
+    pub verified_address: Option<Address>,
+    pub verified_email: Option<String>,
+    pub verified_name: Option<String>,
+    pub verified_phone: Option<String>,
+}
+
+/// Information related to the redirect flow of a source.
+///
+/// For more details see https://stripe.com/docs/api#source_object-redirect.
+#[derive(Debug, Deserialize)]
+pub struct SourceRedirect {
+    pub failure_reason: Option<SourceRedirectFailureReason>,
+    pub return_url: String,
+    pub status: SourceRedirectStatus,
+    pub url: String,
+}
+
+/// Information related to the receiver flow of a source.
+///
+/// For more details see https://stripe.com/docs/api#source_object-receiver.
+#[derive(Debug, Deserialize)]
+pub struct SourceReceiver {
+    pub address: Option<String>,
+    pub amount_charged: i64,
+    pub amount_received: i64,
+    pub amount_returned: i64,
+    pub refund_attributes_method: Option<SourceRefundAttributesMethod>,
+    pub refund_attributes_status: Option<SourceRefundAttributesStatus>,
+}
+
+/// Information related to the code verification flow of a source.
+///
+/// For more details see https://stripe.com/docs/api#source_object-code_verification.
+#[derive(Debug, Deserialize)]
+pub struct SourceCodeVerification {
+    pub attempts_remaining: i64,
+    pub status: SourceCodeVerificationStatus,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceAchCreditTransfer {
+    pub account_number: Option<String>,
+    pub bank_name: Option<String>,
+    pub fingerprint: Option<String>,
+    pub routing_number: Option<String>,
+    pub swift_code: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceAlipay {
+    pub data_string: Option<String>,
+    pub native_url: Option<String>,
+    pub statement_descriptor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceBancontact {
+    pub bank_code: Option<String>,
+    pub bank_name: Option<String>,
+    pub bic: Option<String>,
+    pub iban_last4: Option<String>,
+    pub preferred_language: Option<String>,
+    pub statement_descriptor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceCard {
+    pub address_line1_check: Option<String>, // (pass, fail, unavailable, unchecked)
+    pub address_zip_check: Option<String>, // (pass, fail, unavailable, unchecked)
+    pub brand: Option<String>,
+    pub country: Option<String>,
+    pub cvc_check: Option<String>, // (pass, fail, unavailable, unchecked)
+    pub dynamic_last4: Option<String>,
+    pub exp_month: Option<u32>,
+    pub exp_year: Option<u32>,
+    pub fingerprint: Option<String>,
+    pub funding: Option<String>, // (credit, debit, prepaid, unknown)
+    pub last4: Option<String>,
+    pub three_d_secure: Option<String>, // (not_supported, optional, required)
+    pub tokenization_method: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceGiropay {
+    pub bank_code: Option<String>,
+    pub bank_name: Option<String>,
+    pub bic: Option<String>,
+    pub statement_descriptor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceIdeal {
+    pub bank: Option<String>,
+    pub bic: Option<String>,
+    pub iban_last4: Option<String>,
+    pub statement_descriptor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceSepaDebit {
+    pub bank_code: Option<String>,
+    pub branch_code: Option<String>,
+    pub country: Option<String>,
+    pub fingerprint: Option<String>,
+    pub last4: Option<String>,
+    pub mandate_reference: Option<String>,
+    pub mandate_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceSofort {
+    pub bank_code: Option<String>,
+    pub bank_name: Option<String>,
+    pub bic: Option<String>,
+    pub country: Option<String>,
+    pub iban_last4: Option<String>,
+    pub preferred_language: Option<String>,
+    pub statement_descriptor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceThreeDSecure {
+    pub authenticated: Option<bool>,
+    pub brand: Option<String>,
+    pub card: Option<String>, // the id of the card source
+    pub country: Option<String>,
+    pub customer: Option<String>,
+    pub exp_month: Option<u32>,
+    pub exp_year: Option<u32>,
+    pub fingerprint: Option<String>,
+    pub funding: Option<String>,
+    pub last4: Option<String>,
+    pub three_d_secure: Option<String>, // (not_supported, optional, required)
+}
+
+/// The resource representing a Stripe source.
+///
+/// Only the field matching the `source_type` of the source (eg. `card` for `SourceKind::Card`) is set.
+///
+/// For more details see https://stripe.com/docs/api#source_object.
+#[derive(Debug, Deserialize)]
+pub struct Source {
+    pub id: String,
+    pub amount: Option<u64>,
+    pub client_secret: String,
+    pub code_verification: Option<SourceCodeVerification>,
+    pub created: Timestamp,
+    pub currency: Option<Currency>,
+    pub customer: Option<String>,
+    pub flow: SourceFlow,
+    pub livemode: bool,
+    #[serde(default)]
+    pub metadata: Metadata,
+    pub owner: Option<SourceOwner>,
+    pub receiver: Option<SourceReceiver>,
+    pub redirect: Option<SourceRedirect>,
+    pub statement_descriptor: Option<String>,
+    pub status: SourceStatus,
+    #[serde(rename = "type")]
+    pub source_type: SourceKind,
+    pub usage: Option<SourceUsage>,
+
+    pub ach_credit_transfer: Option<SourceAchCreditTransfer>,
+    pub alipay: Option<SourceAlipay>,
+    pub bancontact: Option<SourceBancontact>,
+    pub card: Option<SourceCard>,
+    pub giropay: Option<SourceGiropay>,
+    pub ideal: Option<SourceIdeal>,
+    pub sepa_debit: Option<SourceSepaDebit>,
+    pub sofort: Option<SourceSofort>,
+    pub three_d_secure: Option<SourceThreeDSecure>,
+}
+
+/// The resource representing a Stripe source transaction (ie. funds pushed to a receiver source).
+///
+/// For more details see https://stripe.com/docs/api#source_transaction_object.
+#[derive(Debug, Deserialize)]
+pub struct SourceTransaction {
+    pub id: String,
+    pub object: String,
+    pub amount: i64,
+    pub created: Timestamp,
+    pub currency: Currency,
+    pub livemode: bool,
+    pub source: String,
+    pub status: Option<String>,
+    #[serde(rename = "type")]
+    pub source_type: SourceKind,
+}
+
+/// A payment source of a charge or customer, which can be a card, a bank account or a source.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum PaymentSource {
     BankAccount(BankAccount),
+    Card(Card),
+    Source(Source),
+}
+
+impl Identifiable for PaymentSource {
+    fn id(&self) -> &str {
+        match *self {
+            PaymentSource::BankAccount(ref bank_account) => &bank_account.id,
+            PaymentSource::Card(ref card) => &card.id,
+            PaymentSource::Source(ref source) => &source.id,
+        }
+    }
 }
 
 impl Source {
+    /// Creates a new source.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_source.
     pub fn create(client: &Client, params: SourceParams) -> Result<Source, Error> {
         client.post_with_params("/sources", params)
     }
 
-    pub fn get_with_params(client: &Client, source_id: &str) -> Result<Source, Error> {
-        client.get(&format!("/sources/{}", source_id))
+    /// Retrieves the details of a source.
+    ///
+    /// The `client_secret` is required when retrieving a source with a publishable key.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_source.
+    pub fn retrieve(client: &Client, source_id: &str, client_secret: Option<&str>) -> Result<Source, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            client_secret: Option<&'a str>,
+        }
+
+        client.get_with_params(&format!("/sources/{}", source_id), Params { client_secret: client_secret })
+    }
+
+    /// Updates a source's metadata and owner.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_source.
+    pub fn update(client: &Client, source_id: &str, params: SourceUpdateParams) -> Result<Source, Error> {
+        client.post_with_params(&format!("/sources/{}", source_id), params)
+    }
+
+    /// Attaches a reusable source to a customer.
+    ///
+    /// For more details see https://stripe.com/docs/api#attach_source.
+    pub fn attach(client: &Client, customer_id: &str, source_id: &str) -> Result<Source, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            source: &'a str,
+        }
+
+        client.post_with_params(&format!("/customers/{}/sources", customer_id), Params { source: source_id })
+    }
+
+    /// Detaches a source from a customer, after which it can no longer be used.
+    ///
+    /// For more details see https://stripe.com/docs/api#detach_source.
+    pub fn detach(client: &Client, customer_id: &str, source_id: &str) -> Result<Source, Error> {
+        client.delete(&format!("/customers/{}/sources/{}", customer_id, source_id))
     }
 
-    pub fn update(client: &Client, source_id: &str, params: SourceParams) -> Result<Source, Error> {
-        client.post_with_params(&format!("/source/{}", source_id), params)
+    /// Lists the transactions of a receiver source.
+    ///
+    /// For more details see https://stripe.com/docs/api#source_transactions.
+    pub fn list_transactions(
+        client: &Client,
+        source_id: &str,
+        params: SourceTransactionListParams,
+    ) -> Result<List<SourceTransaction>, Error> {
+        client.get_with_params(&format!("/sources/{}/source_transactions", source_id), params)
     }
 }
@@ -1,7 +1,7 @@
 use error::Error;
 use client::Client;
-use resources::{Discount, Plan};
-use params::{List, Metadata, Timestamp};
+use resources::{Discount, Plan, SubscriptionItem};
+use params::{List, Metadata, RangeQuery, Timestamp};
 
 #[derive(Default, Serialize)]
 pub struct CancelParams {
@@ -57,15 +57,57 @@ pub enum TrialEnd<'a> {
     Special(&'a str)
 }
 
-/// The resource representing a Stripe subscription item.
+/// The set of parameters that can be used when listing subscriptions.
 ///
-/// For more details see https://stripe.com/docs/api#subscription_items.
-#[derive(Debug, Deserialize)]
-pub struct SubscriptionItem {
-    pub id: String,
-    pub created: Timestamp,
-    pub plan: Plan,
-    pub quantity: u64,
+/// For more details see https://stripe.com/docs/api#list_subscriptions.
+#[derive(Default, Serialize)]
+pub struct SubscriptionListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<SubscriptionStatusFilter>,
+}
+
+string_enum! {
+    /// An enum representing the possible values of a `Subscription`'s `status` field.
+    ///
+    /// For more details see https://stripe.com/docs/api#subscription_object-status.
+    pub enum SubscriptionStatus {
+        Active => "active",
+        Canceled => "canceled",
+        Incomplete => "incomplete",
+        IncompleteExpired => "incomplete_expired",
+        PastDue => "past_due",
+        Trialing => "trialing",
+        Unpaid => "unpaid",
+    }
+}
+
+/// The statuses that subscriptions can be filtered by when listing them.
+///
+/// By default Stripe lists all subscriptions that have not been canceled.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubscriptionStatusFilter {
+    Active,
+    All,
+    Canceled,
+    Ended,
+    Incomplete,
+    IncompleteExpired,
+    PastDue,
+    Trialing,
+    Unpaid,
 }
 
 /// The resource representing a Stripe subscription.
@@ -89,7 +131,7 @@ pub struct Subscription {
     pub plan: Plan,
     pub quantity: u64,
     pub start: Timestamp,
-    pub status: String, // (trialing, active, past_due, canceled, unpaid)
+    pub status: SubscriptionStatus,
     pub tax_percent: Option<f64>,
     pub trial_start: Option<Timestamp>,
     pub trial_end: Option<Timestamp>,
@@ -122,4 +164,11 @@ impl Subscription {
     pub fn cancel(client: &Client, subscription_id: &str, params: CancelParams) -> Result<Subscription, Error> {
         client.delete_with_params(&format!("/subscriptions/{}", subscription_id), params)
     }
+
+    /// Lists subscriptions, by default only those which have not been canceled.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_subscriptions.
+    pub fn list(client: &Client, params: SubscriptionListParams) -> Result<List<Subscription>, Error> {
+        client.get_with_params("/subscriptions", params)
+    }
 }
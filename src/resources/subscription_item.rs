@@ -0,0 +1,124 @@
+use error::Error;
+use client::Client;
+use params::{List, Metadata, Timestamp};
+use resources::{Deleted, Plan};
+
+/// The set of parameters that can be used when creating a subscription item.
+///
+/// For more details see https://stripe.com/docs/api#create_subscription_item.
+#[derive(Default, Serialize)]
+pub struct SubscriptionItemCreateParams<'a> {
+    pub subscription: &'a str, // this is a required param
+    pub plan: &'a str, // this is a required param
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prorate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+}
+
+/// The set of parameters that can be used when updating a subscription item.
+///
+/// For more details see https://stripe.com/docs/api#update_subscription_item.
+#[derive(Default, Serialize)]
+pub struct SubscriptionItemUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plan: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prorate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_date: Option<Timestamp>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<u64>,
+}
+
+/// The set of parameters that can be used when deleting a subscription item.
+///
+/// For more details see https://stripe.com/docs/api#delete_subscription_item.
+#[derive(Default, Serialize)]
+pub struct SubscriptionItemDeleteParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clear_usage: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prorate: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proration_date: Option<Timestamp>,
+}
+
+/// The set of parameters that can be used when listing subscription items.
+///
+/// For more details see https://stripe.com/docs/api#list_subscription_items.
+#[derive(Default, Serialize)]
+pub struct SubscriptionItemListParams<'a> {
+    pub subscription: &'a str, // this is a required param
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+/// The resource representing a Stripe subscription item.
+///
+/// For more details see https://stripe.com/docs/api#subscription_items.
+#[derive(Debug, Deserialize)]
+pub struct SubscriptionItem {
+    pub id: String,
+    pub created: Timestamp,
+    #[serde(default)]
+    pub metadata: Metadata,
+    pub plan: Plan,
+    pub quantity: Option<u64>, // NOTE: not present for metered plans
+    pub subscription: Option<String>,
+}
+
+impl SubscriptionItem {
+    /// Adds a new item to an existing subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_subscription_item.
+    pub fn create(client: &Client, params: SubscriptionItemCreateParams) -> Result<SubscriptionItem, Error> {
+        client.post_with_params("/subscription_items", params)
+    }
+
+    /// Retrieves the details of a subscription item.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_subscription_item.
+    pub fn retrieve(client: &Client, subscription_item_id: &str) -> Result<SubscriptionItem, Error> {
+        client.get(&format!("/subscription_items/{}", subscription_item_id))
+    }
+
+    /// Updates a subscription item's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_subscription_item.
+    pub fn update(
+        client: &Client,
+        subscription_item_id: &str,
+        params: SubscriptionItemUpdateParams,
+    ) -> Result<SubscriptionItem, Error> {
+        client.post_with_params(&format!("/subscription_items/{}", subscription_item_id), params)
+    }
+
+    /// Deletes an item from its subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api#delete_subscription_item.
+    pub fn delete(
+        client: &Client,
+        subscription_item_id: &str,
+        params: SubscriptionItemDeleteParams,
+    ) -> Result<Deleted, Error> {
+        client.delete_with_params(&format!("/subscription_items/{}", subscription_item_id), params)
+    }
+
+    /// Lists the items of a subscription.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_subscription_items.
+    pub fn list(client: &Client, params: SubscriptionItemListParams) -> Result<List<SubscriptionItem>, Error> {
+        client.get_with_params("/subscription_items", params)
+    }
+}
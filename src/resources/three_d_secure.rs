@@ -0,0 +1,181 @@
+use error::Error;
+use client::Client;
+use params::Metadata;
+use resources::{Currency, Event, EventObject, EventType, RedirectParams, Source, SourceKind, SourceParams,
+                SourceRedirectStatus, SourceStatus, ThreeDSecureParams};
+
+/// The states of a 3D Secure payment flow.
+///
+/// A flow starts as `Created`, moves to `PendingRedirect` once the `three_d_secure` source
+/// has been created, then to `AwaitingChargeable` when the customer completes authentication
+/// (or it isn't required), to `Chargeable` on the `source.chargeable` event, and finally
+/// to `Consumed` once it has been charged.
+/// It is `Failed` if authentication fails or the source is canceled.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ThreeDSecureState {
+    Created,
+    PendingRedirect,
+    AwaitingChargeable,
+    Chargeable,
+    Consumed,
+    Failed,
+}
+
+impl ThreeDSecureState {
+    /// Returns the state of a 3D Secure flow given its `three_d_secure` source.
+    pub fn of(source: &Source) -> ThreeDSecureState {
+        match source.status {
+            SourceStatus::Chargeable => ThreeDSecureState::Chargeable,
+            SourceStatus::Consumed => ThreeDSecureState::Consumed,
+            SourceStatus::Canceled | SourceStatus::Failed => ThreeDSecureState::Failed,
+            // NOTE: a status not known to this crate is treated as pending until the source is updated
+            SourceStatus::Pending | SourceStatus::Other(_) => match source.redirect.as_ref().map(|r| &r.status) {
+                Some(&SourceRedirectStatus::Failed) => ThreeDSecureState::Failed,
+                Some(&SourceRedirectStatus::NotRequired) | Some(&SourceRedirectStatus::Succeeded) => {
+                    ThreeDSecureState::AwaitingChargeable
+                }
+                _ => ThreeDSecureState::PendingRedirect,
+            },
+        }
+    }
+}
+
+/// The set of parameters that can be used when starting a 3D Secure flow.
+pub struct ThreeDSecureFlowParams<'a> {
+    pub amount: u64,
+    pub currency: Currency,
+    pub return_url: &'a str, // the customer is redirected here after authenticating
+    pub customer: Option<&'a str>, // NOTE: required if the card source is attached to a customer
+    pub metadata: Option<Metadata>,
+}
+
+/// A helper driving a card payment through 3D Secure authentication.
+///
+/// Because the flow spans a redirect and a webhook, it can be rebuilt from its source at any step
+/// with `ThreeDSecureFlow::from_source`.
+///
+/// For more details see https://stripe.com/docs/sources/three-d-secure.
+#[derive(Debug)]
+pub struct ThreeDSecureFlow {
+    card_source_id: String,
+    source: Option<Source>,
+}
+
+impl ThreeDSecureFlow {
+    /// Returns whether 3D Secure is required by the issuer of a card source.
+    ///
+    /// When it is "optional" the card can be charged directly, though running the flow shifts liability.
+    pub fn is_required(card: &Source) -> bool {
+        card.card.as_ref().and_then(|card| card.three_d_secure.as_ref()).map_or(false, |s| s == "required")
+    }
+
+    /// Begins a new flow for the card source with the given id.
+    pub fn new<Str: Into<String>>(card_source_id: Str) -> ThreeDSecureFlow {
+        ThreeDSecureFlow { card_source_id: card_source_id.into(), source: None }
+    }
+
+    /// Resumes a flow from its `three_d_secure` source (eg. when handling the redirect or a webhook).
+    pub fn from_source(source: Source) -> ThreeDSecureFlow {
+        let card_source_id = source.three_d_secure.as_ref().and_then(|data| data.card.clone()).unwrap_or_default();
+        ThreeDSecureFlow { card_source_id: card_source_id, source: Some(source) }
+    }
+
+    pub fn state(&self) -> ThreeDSecureState {
+        match self.source {
+            Some(ref source) => ThreeDSecureState::of(source),
+            None => ThreeDSecureState::Created,
+        }
+    }
+
+    pub fn card_source_id(&self) -> &str {
+        &self.card_source_id
+    }
+
+    /// The `three_d_secure` source, once it has been created.
+    pub fn source(&self) -> Option<&Source> {
+        self.source.as_ref()
+    }
+
+    /// The url the customer must be redirected to while the flow is `PendingRedirect`.
+    ///
+    /// Returns `None` once the customer has been through the redirect, even though the source
+    /// may stay pending until the `source.chargeable` event arrives.
+    pub fn redirect_url(&self) -> Option<&str> {
+        if self.state() != ThreeDSecureState::PendingRedirect {
+            return None;
+        }
+        match self.source.as_ref().and_then(|source| source.redirect.as_ref()) {
+            Some(redirect) if redirect.status == SourceRedirectStatus::Pending => Some(redirect.url.as_str()),
+            _ => None,
+        }
+    }
+
+    /// The id of the source to charge while the flow is `Chargeable`.
+    pub fn chargeable_source_id(&self) -> Option<&str> {
+        match self.source {
+            Some(ref source) if self.state() == ThreeDSecureState::Chargeable => Some(&source.id),
+            _ => None,
+        }
+    }
+
+    /// Creates the `three_d_secure` source, moving a `Created` flow to `PendingRedirect`.
+    ///
+    /// Does nothing if the source was already created.
+    pub fn start(&mut self, client: &Client, params: ThreeDSecureFlowParams) -> Result<ThreeDSecureState, Error> {
+        if self.source.is_none() {
+            let source = Source::create(
+                client,
+                SourceParams {
+                    source_type: Some(SourceKind::ThreeDSecure),
+                    amount: Some(params.amount),
+                    currency: Some(params.currency),
+                    metadata: params.metadata,
+                    redirect: Some(RedirectParams { return_url: params.return_url }),
+                    three_d_secure: Some(ThreeDSecureParams {
+                        card: &self.card_source_id,
+                        customer: params.customer,
+                    }),
+                    ..Default::default()
+                },
+            )?;
+            self.source = Some(source);
+        }
+        Ok(self.state())
+    }
+
+    /// Updates the flow from a `source.chargeable`, `source.failed` or `source.canceled` webhook event.
+    ///
+    /// Other events, and events for other sources, are ignored.
+    pub fn handle_event(&mut self, event: Event) -> ThreeDSecureState {
+        match event.event_type {
+            EventType::Sourcechargeable | EventType::SourceFailed | EventType::SourceCanceled => {}
+            _ => return self.state(),
+        }
+        if let EventObject::Source(source) = event.data.object {
+            if self.is_own_source(&source) {
+                self.source = Some(source);
+            }
+        }
+        self.state()
+    }
+
+    /// Retrieves the latest version of the `three_d_secure` source (eg. when the customer returns from the redirect).
+    pub fn refresh(&mut self, client: &Client) -> Result<ThreeDSecureState, Error> {
+        let source = match self.source {
+            Some(ref source) => Source::retrieve(client, &source.id, None)?,
+            None => return Ok(ThreeDSecureState::Created),
+        };
+        self.source = Some(source);
+        Ok(self.state())
+    }
+
+    fn is_own_source(&self, source: &Source) -> bool {
+        match self.source {
+            Some(ref own) => own.id == source.id,
+            None => {
+                source.source_type == SourceKind::ThreeDSecure &&
+                    source.three_d_secure.as_ref().and_then(|data| data.card.as_ref()) == Some(&self.card_source_id)
+            }
+        }
+    }
+}
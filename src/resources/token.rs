@@ -0,0 +1,90 @@
+use error::Error;
+use client::Client;
+use params::Timestamp;
+use resources::{BankAccount, BankAccountParams, Card, CardParams, LegalEntityParams};
+
+/// An enum representing the possible values of a `Token`'s `type` field.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenType {
+    Account,
+    BankAccount,
+    Card,
+    Pii,
+}
+
+/// The personally identifiable information that can be tokenized.
+///
+/// For more details see https://stripe.com/docs/api#create_pii_token.
+#[derive(Serialize)]
+pub struct PiiParams<'a> {
+    pub personal_id_number: &'a str,
+}
+
+/// The account information that can be tokenized.
+///
+/// For more details see https://stripe.com/docs/api#create_account_token.
+#[derive(Default, Serialize)]
+pub struct AccountTokenParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_entity: Option<LegalEntityParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tos_shown_and_accepted: Option<bool>,
+}
+
+/// The set of parameters that can be used when creating a token.
+///
+/// Exactly one of `card`, `bank_account`, `pii` or `account` should be set.
+/// When sharing a customer's card or bank account with a connected account,
+/// set `customer` (and optionally `card` or `bank_account` to the id of the source).
+///
+/// For more details see https://stripe.com/docs/api#tokens.
+#[derive(Default, Serialize)]
+pub struct TokenParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account: Option<AccountTokenParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_account: Option<BankAccountParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub card: Option<CardParams<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pii: Option<PiiParams<'a>>,
+}
+
+/// The resource representing a Stripe token.
+///
+/// For more details see https://stripe.com/docs/api#token_object.
+#[derive(Debug, Deserialize)]
+pub struct Token {
+    pub id: String,
+    pub object: String,
+    pub bank_account: Option<BankAccount>,
+    pub card: Option<Card>,
+    pub client_ip: Option<String>,
+    pub created: Timestamp,
+    pub livemode: bool,
+    #[serde(rename = "type")]
+    pub token_type: TokenType,
+    pub used: bool,
+}
+
+impl Token {
+    /// Creates a single-use token for a card, bank account, PII or account details.
+    ///
+    /// Tokens can be created with either a publishable key or a secret key,
+    /// so a client constructed with `Client::new("pk_...")` may be used.
+    ///
+    /// For more details see https://stripe.com/docs/api#tokens.
+    pub fn create(client: &Client, params: TokenParams) -> Result<Token, Error> {
+        client.post_with_params("/tokens", params)
+    }
+
+    /// Retrieves the details of a token.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_token.
+    pub fn retrieve(client: &Client, token_id: &str) -> Result<Token, Error> {
+        client.get(&format!("/tokens/{}", token_id))
+    }
+}
@@ -1,6 +1,97 @@
-use params::{List, Metadata, Timestamp};
+use error::Error;
+use client::Client;
+use params::{List, Metadata, RangeQuery, Timestamp};
 use resources::Currency;
 
+/// The set of parameters that can be used when creating a transfer.
+///
+/// For more details see https://stripe.com/docs/api#create_transfer.
+#[derive(Default, Serialize)]
+pub struct TransferParams<'a> {
+    pub amount: u64,
+    pub currency: Currency,
+    pub destination: &'a str, // the id of a connected account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_transaction: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when updating a transfer.
+///
+/// For more details see https://stripe.com/docs/api#update_transfer.
+#[derive(Default, Serialize)]
+pub struct TransferUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing transfers.
+///
+/// For more details see https://stripe.com/docs/api#list_transfers.
+#[derive(Default, Serialize)]
+pub struct TransferListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<RangeQuery<Timestamp>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transfer_group: Option<&'a str>,
+}
+
+/// The set of parameters that can be used when reversing a transfer.
+///
+/// If `amount` is `None`, the entire remaining amount of the transfer is reversed.
+///
+/// For more details see https://stripe.com/docs/api#create_transfer_reversal.
+#[derive(Default, Serialize)]
+pub struct TransferReversalParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refund_application_fee: Option<bool>,
+}
+
+/// The set of parameters that can be used when updating a transfer reversal.
+///
+/// For more details see https://stripe.com/docs/api#update_transfer_reversal.
+#[derive(Default, Serialize)]
+pub struct TransferReversalUpdateParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+}
+
+/// The set of parameters that can be used when listing the reversals of a transfer.
+///
+/// For more details see https://stripe.com/docs/api#list_transfer_reversals.
+#[derive(Default, Serialize)]
+pub struct TransferReversalListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
 /// The resource representing a Stripe transfer reversal.
 ///
 /// For more details see https://stripe.com/docs/api#transfer_reversal_object.
@@ -9,7 +100,7 @@ pub struct TransferReversal {
     pub id: String,
     pub object: String,
     pub amount: u64,
-    pub balance_transaction: String,
+    pub balance_transaction: Option<String>,
     pub created: Timestamp,
     pub currency: Currency,
     pub metadata: Metadata,
@@ -25,17 +116,86 @@ pub struct Transfer {
     pub object: String,
     pub amount: u64,
     pub amount_reversed: u64,
-    pub balance_transaction: String,
+    pub balance_transaction: Option<String>,
     pub created: Timestamp,
     pub currency: Currency,
     pub description: Option<String>,
-    pub destination: String,
-    pub destination_payment: String,
+    pub destination: Option<String>,
+    pub destination_payment: Option<String>,
     pub livemode: bool,
     pub metadata: Metadata,
     pub reversals: List<TransferReversal>,
     pub reversed: bool,
-    pub source_transaction: String,
-    pub source_type: String,
-    pub transfer_group: String,
+    pub source_transaction: Option<String>,
+    pub source_type: Option<String>, // (card, bank_account, alipay_account)
+    pub transfer_group: Option<String>,
+}
+
+impl Transfer {
+    /// Creates a new transfer to a connected account.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_transfer.
+    pub fn create(client: &Client, params: TransferParams) -> Result<Transfer, Error> {
+        client.post_with_params("/transfers", params)
+    }
+
+    /// Retrieves the details of a transfer.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_transfer.
+    pub fn retrieve(client: &Client, transfer_id: &str) -> Result<Transfer, Error> {
+        client.get(&format!("/transfers/{}", transfer_id))
+    }
+
+    /// Updates a transfer's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_transfer.
+    pub fn update(client: &Client, transfer_id: &str, params: TransferUpdateParams) -> Result<Transfer, Error> {
+        client.post_with_params(&format!("/transfers/{}", transfer_id), params)
+    }
+
+    /// Lists all transfers.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_transfers.
+    pub fn list(client: &Client, params: TransferListParams) -> Result<List<Transfer>, Error> {
+        client.get_with_params("/transfers", params)
+    }
+}
+
+impl TransferReversal {
+    /// Reverses all or part of a transfer.
+    ///
+    /// For more details see https://stripe.com/docs/api#create_transfer_reversal.
+    pub fn create(client: &Client, transfer_id: &str, params: TransferReversalParams) -> Result<TransferReversal, Error> {
+        client.post_with_params(&format!("/transfers/{}/reversals", transfer_id), params)
+    }
+
+    /// Retrieves the details of a transfer reversal.
+    ///
+    /// For more details see https://stripe.com/docs/api#retrieve_transfer_reversal.
+    pub fn retrieve(client: &Client, transfer_id: &str, reversal_id: &str) -> Result<TransferReversal, Error> {
+        client.get(&format!("/transfers/{}/reversals/{}", transfer_id, reversal_id))
+    }
+
+    /// Updates a transfer reversal's properties.
+    ///
+    /// For more details see https://stripe.com/docs/api#update_transfer_reversal.
+    pub fn update(
+        client: &Client,
+        transfer_id: &str,
+        reversal_id: &str,
+        params: TransferReversalUpdateParams,
+    ) -> Result<TransferReversal, Error> {
+        client.post_with_params(&format!("/transfers/{}/reversals/{}", transfer_id, reversal_id), params)
+    }
+
+    /// Lists the reversals of a transfer.
+    ///
+    /// For more details see https://stripe.com/docs/api#list_transfer_reversals.
+    pub fn list(
+        client: &Client,
+        transfer_id: &str,
+        params: TransferReversalListParams,
+    ) -> Result<List<TransferReversal>, Error> {
+        client.get_with_params(&format!("/transfers/{}/reversals", transfer_id), params)
+    }
 }
@@ -0,0 +1,94 @@
+use error::Error;
+use client::Client;
+use params::{List, Timestamp};
+
+/// An enum representing how a usage record's quantity is applied to the billing period.
+#[derive(Debug, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageRecordAction {
+    Increment,
+    Set,
+}
+
+/// The set of parameters that can be used when creating a usage record.
+///
+/// To safely retry the request, send it with `client.with_idempotency_key(..)`.
+///
+/// For more details see https://stripe.com/docs/api#usage_record_create.
+#[derive(Default, Serialize)]
+pub struct UsageRecordParams {
+    pub quantity: u64,
+    pub timestamp: Timestamp,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<UsageRecordAction>, // NOTE: if None, Stripe assumes increment
+}
+
+/// The set of parameters that can be used when listing usage record summaries.
+///
+/// For more details see https://stripe.com/docs/api#usage_record_summary_list.
+#[derive(Default, Serialize)]
+pub struct UsageRecordSummaryListParams<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ending_before: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub starting_after: Option<&'a str>,
+}
+
+/// The resource representing a Stripe usage record.
+///
+/// For more details see https://stripe.com/docs/api#usage_record_object.
+#[derive(Debug, Deserialize)]
+pub struct UsageRecord {
+    pub id: String,
+    pub livemode: bool,
+    pub quantity: u64,
+    pub subscription_item: String,
+    pub timestamp: Timestamp,
+}
+
+/// The billing period covered by a usage record summary.
+#[derive(Debug, Deserialize)]
+pub struct UsagePeriod {
+    pub start: Option<Timestamp>,
+    pub end: Option<Timestamp>,
+}
+
+/// The resource representing a summary of the usage records of a subscription item for one billing period.
+///
+/// For more details see https://stripe.com/docs/api#usage_record_summary_object.
+#[derive(Debug, Deserialize)]
+pub struct UsageRecordSummary {
+    pub id: String,
+    pub invoice: Option<String>,
+    pub livemode: bool,
+    pub period: UsagePeriod,
+    pub subscription_item: String,
+    pub total_usage: u64,
+}
+
+impl UsageRecord {
+    /// Reports usage of a metered subscription item.
+    ///
+    /// For more details see https://stripe.com/docs/api#usage_record_create.
+    pub fn create(client: &Client, subscription_item_id: &str, params: UsageRecordParams) -> Result<UsageRecord, Error> {
+        client.post_with_params(&format!("/subscription_items/{}/usage_records", subscription_item_id), params)
+    }
+}
+
+impl UsageRecordSummary {
+    /// Lists the usage summaries of a subscription item, one per billing period.
+    ///
+    /// For more details see https://stripe.com/docs/api#usage_record_summary_list.
+    pub fn list(
+        client: &Client,
+        subscription_item_id: &str,
+        params: UsageRecordSummaryListParams,
+    ) -> Result<List<UsageRecordSummary>, Error> {
+        client.get_with_params(
+            &format!("/subscription_items/{}/usage_record_summaries", subscription_item_id),
+            params,
+        )
+    }
+}
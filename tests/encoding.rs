@@ -32,4 +32,324 @@ fn deserialize_currency() {
     assert_eq!(json::from_str::<Currency>("\"aed\"").unwrap(), Currency::AED);
     assert_eq!(json::from_str::<Currency>("\"usd\"").unwrap(), Currency::USD);
     assert_eq!(json::from_str::<Currency>("\"zmw\"").unwrap(), Currency::ZMW);
-}
\ No newline at end of file
+}
+
+#[test]
+fn serialize_range_query() {
+    use stripe::{ChargeListParams, RangeQuery};
+    let params = ChargeListParams { created: Some(RangeQuery::gte(1500000000)), limit: Some(10), ..Default::default() };
+    assert_eq!(qs::to_string(&params).unwrap(), "created[gte]=1500000000&limit=10");
+    let params = ChargeListParams { created: Some(RangeQuery::eq(1500000000)), ..Default::default() };
+    assert_eq!(qs::to_string(&params).unwrap(), "created=1500000000");
+}
+
+#[test]
+fn serialize_metadata() {
+    use stripe::{CustomerParams, Metadata};
+    let mut metadata = Metadata::new();
+    metadata.insert("order_id".to_string(), "6735".to_string());
+    let params = CustomerParams { email: Some("jenny@example.com"), metadata: Some(metadata), ..Default::default() };
+    assert_eq!(qs::to_string(&params).unwrap(), "email=jenny%40example.com&metadata[order_id]=6735");
+}
+
+#[test]
+fn serialize_dispute_evidence() {
+    use stripe::{DisputeEvidenceObject, DisputeParams};
+    let params = DisputeParams {
+        evidence: Some(DisputeEvidenceObject {
+            customer_name: Some("Jenny Rosen".to_string()),
+            uncategorized_text: Some("Shipped & delivered".to_string()),
+            ..Default::default()
+        }),
+        submit: Some(false),
+        ..Default::default()
+    };
+    assert_eq!(
+        qs::to_string(&params).unwrap(),
+        "evidence[customer_name]=Jenny+Rosen&evidence[uncategorized_text]=Shipped+%26+delivered&submit=false"
+    );
+}
+
+#[test]
+fn serialize_tiered_plan() {
+    use stripe::{Currency, PlanInterval, PlanParams, PlanProduct, PlanProductParams, PlanTierParams, PlanTiersMode,
+                 PlanUsageType, TransformUsage, TransformUsageRound, UpTo};
+    let params = PlanParams {
+        currency: Some(Currency::USD),
+        interval: Some(PlanInterval::Month),
+        product: Some(PlanProduct::Product(PlanProductParams { name: "API calls", ..Default::default() })),
+        tiers: Some(vec![
+            PlanTierParams { up_to: UpTo::Max(1000), amount: Some(10), flat_amount: None },
+            PlanTierParams { up_to: UpTo::Inf, amount: Some(5), flat_amount: Some(100) },
+        ]),
+        tiers_mode: Some(PlanTiersMode::Graduated),
+        transform_usage: Some(TransformUsage { divide_by: 100, round: TransformUsageRound::Up }),
+        usage_type: Some(PlanUsageType::Metered),
+        ..Default::default()
+    };
+    assert_eq!(
+        qs::to_string(&params).unwrap(),
+        "currency=usd&interval=month&product[name]=API+calls\
+         &tiers[0][up_to]=1000&tiers[0][amount]=10\
+         &tiers[1][up_to]=inf&tiers[1][amount]=5&tiers[1][flat_amount]=100\
+         &tiers_mode=graduated&transform_usage[divide_by]=100&transform_usage[round]=up&usage_type=metered"
+    );
+}
+
+#[test]
+fn deserialize_up_to() {
+    use stripe::UpTo;
+    assert_eq!(json::from_str::<UpTo>("1000").unwrap(), UpTo::Max(1000));
+    assert_eq!(json::from_str::<UpTo>("\"inf\"").unwrap(), UpTo::Inf);
+    assert!(json::from_str::<UpTo>("\"infinity\"").is_err());
+}
+
+#[test]
+fn serialize_order_items() {
+    use stripe::{Currency, OrderItemParams, OrderItemType, OrderParams};
+    let params = OrderParams {
+        currency: Currency::USD,
+        items: Some(vec![
+            OrderItemParams {
+                item_type: Some(OrderItemType::Sku),
+                parent: Some("sku_1"),
+                quantity: Some(2),
+                ..Default::default()
+            },
+            OrderItemParams { item_type: Some(OrderItemType::Sku), parent: Some("sku_2"), ..Default::default() },
+        ]),
+        ..Default::default()
+    };
+    assert_eq!(
+        qs::to_string(&params).unwrap(),
+        "currency=usd&items[0][parent]=sku_1&items[0][quantity]=2&items[0][type]=sku\
+         &items[1][parent]=sku_2&items[1][type]=sku"
+    );
+}
+
+#[test]
+fn serialize_order_list_filters() {
+    use stripe::{OrderListParams, RangeQuery, StatusTransitionsFilter};
+    let params = OrderListParams {
+        ids: Some(vec!["or_1", "or_2"]),
+        status_transitions: Some(StatusTransitionsFilter {
+            paid: Some(RangeQuery::gte(1530000000)),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        qs::to_string(&params).unwrap(),
+        "ids[0]=or_1&ids[1]=or_2&status_transitions[paid][gte]=1530000000"
+    );
+}
+
+#[test]
+fn deserialize_balance_transaction_type() {
+    use stripe::BalanceTransactionType;
+    assert_eq!(
+        json::from_str::<BalanceTransactionType>("\"stripe_fee\"").unwrap(),
+        BalanceTransactionType::StripeFee
+    );
+    assert_eq!(
+        json::from_str::<BalanceTransactionType>("\"issuing_authorization_hold\"").unwrap(),
+        BalanceTransactionType::Other("issuing_authorization_hold".to_string())
+    );
+    assert_eq!(json::to_string(&BalanceTransactionType::PayoutCancel).unwrap(), "\"payout_cancel\"");
+}
+
+#[test]
+fn serialize_payment_intent_params() {
+    use stripe::{CaptureMethod, Currency, PaymentIntentCreateParams};
+    let params = PaymentIntentCreateParams {
+        amount: 1099,
+        currency: Currency::EUR,
+        capture_method: Some(CaptureMethod::Manual),
+        payment_method_types: Some(vec!["card", "ideal"]),
+        ..Default::default()
+    };
+    assert_eq!(
+        qs::to_string(&params).unwrap(),
+        "amount=1099&currency=eur&capture_method=manual&payment_method_types[0]=card&payment_method_types[1]=ideal"
+    );
+}
+
+#[test]
+fn deserialize_payment_intent_next_action() {
+    use stripe::{PaymentIntentNextAction, PaymentIntentNextActionType};
+    let action: PaymentIntentNextAction = json::from_str(r#"{"type": "display_qr_code"}"#).unwrap();
+    assert_eq!(action.action_type, PaymentIntentNextActionType::Other("display_qr_code".to_string()));
+    let action: PaymentIntentNextAction =
+        json::from_str(r#"{"type": "redirect_to_url", "redirect_to_url": {"return_url": null, "url": "https://hooks"}}"#)
+            .unwrap();
+    assert_eq!(action.action_type, PaymentIntentNextActionType::RedirectToUrl);
+}
+
+#[test]
+fn deserialize_payment_source() {
+    use stripe::{PaymentSource, SourceKind};
+    let source: PaymentSource = json::from_str(
+        r#"{"object": "source", "id": "src_123", "client_secret": "src_client_secret_123", "created": 1500000000,
+            "flow": "none", "livemode": false, "status": "chargeable", "type": "klarna"}"#,
+    ).unwrap();
+    match source {
+        PaymentSource::Source(source) => assert_eq!(source.source_type, SourceKind::Other("klarna".to_string())),
+        other => panic!("expected a source, got {:?}", other),
+    }
+    let card: PaymentSource = json::from_str(
+        r#"{"object": "card", "id": "card_123", "brand": "Visa", "country": "US", "exp_month": 8, "exp_year": 2030,
+            "fingerprint": "Xt5EWLLDS7FJjR1c", "funding": "credit", "last4": "4242"}"#,
+    ).unwrap();
+    match card {
+        PaymentSource::Card(card) => assert_eq!(card.last4, "4242"),
+        other => panic!("expected a card, got {:?}", other),
+    }
+    let bank_account: PaymentSource = json::from_str(
+        r#"{"object": "bank_account", "id": "ba_123", "country": "US", "currency": "usd",
+            "fingerprint": "1JWtPxqbdX5Gamtc", "last4": "6789", "status": "new"}"#,
+    ).unwrap();
+    match bank_account {
+        PaymentSource::BankAccount(bank_account) => assert_eq!(bank_account.last4, "6789"),
+        other => panic!("expected a bank account, got {:?}", other),
+    }
+}
+
+#[test]
+fn serialize_product_params() {
+    use stripe::{ProductCreateParams, ProductType};
+    let params = ProductCreateParams {
+        name: "T-shirt",
+        product_type: Some(ProductType::Good),
+        attributes: Some(vec!["size", "gender"]),
+        deactivate_on: Some(vec!["ios"]),
+        images: Some(vec!["https://example.com/shirt.png"]),
+        ..Default::default()
+    };
+    assert_eq!(
+        qs::to_string(&params).unwrap(),
+        "name=T-shirt&type=good&attributes[0]=size&attributes[1]=gender&deactivate_on[0]=ios\
+         &images[0]=https%3A%2F%2Fexample.com%2Fshirt.png"
+    );
+}
+
+#[test]
+fn serialize_sku_inventory() {
+    use stripe::{Currency, Inventory, InventoryType, InventoryValue, Metadata, SkuCreateParams};
+    let mut attributes = Metadata::new();
+    attributes.insert("size".to_string(), "Medium".to_string());
+    let params = SkuCreateParams {
+        product: "prod_1",
+        currency: Some(Currency::USD),
+        inventory: Some(Inventory {
+            inventory_type: InventoryType::Bucket,
+            quantity: None,
+            value: Some(InventoryValue::InStock),
+        }),
+        price: Some(1500),
+        attributes: Some(attributes),
+        ..Default::default()
+    };
+    assert_eq!(
+        qs::to_string(&params).unwrap(),
+        "product=prod_1&currency=usd&inventory[type]=bucket&inventory[value]=in_stock&price=1500\
+         &attributes[size]=Medium"
+    );
+}
+
+#[test]
+fn serialize_invoice_item_period() {
+    use stripe::{InvoiceItemCreateParams, InvoiceItemUpdateParams, Period};
+    let params = InvoiceItemCreateParams {
+        customer: "cus_1",
+        amount: Some(2500),
+        period: Some(Period { start: 1514764800, end: 1517443200 }),
+        ..Default::default()
+    };
+    assert_eq!(
+        qs::to_string(&params).unwrap(),
+        "customer=cus_1&amount=2500&period[start]=1514764800&period[end]=1517443200"
+    );
+    let params = InvoiceItemUpdateParams {
+        period: Some(Period { start: 1514764800, end: 1517443200 }),
+        ..Default::default()
+    };
+    assert_eq!(qs::to_string(&params).unwrap(), "period[start]=1514764800&period[end]=1517443200");
+}
+
+#[test]
+fn serialize_bank_account_verify_params() {
+    use stripe::BankAccountVerifyParams;
+    let params = BankAccountVerifyParams { amounts: [32, 45] };
+    assert_eq!(qs::to_string(&params).unwrap(), "amounts[0]=32&amounts[1]=45");
+}
+
+#[test]
+fn deserialize_dispute_reason() {
+    use stripe::{DisputeReason, DisputeStatus};
+    assert_eq!(json::from_str::<DisputeReason>("\"fraudulent\"").unwrap(), DisputeReason::Fraudulent);
+    assert_eq!(
+        json::from_str::<DisputeReason>("\"noncompliant\"").unwrap(),
+        DisputeReason::Other("noncompliant".to_string())
+    );
+    assert_eq!(
+        json::from_str::<DisputeStatus>("\"prevented\"").unwrap(),
+        DisputeStatus::Other("prevented".to_string())
+    );
+}
+
+#[test]
+fn deserialize_legacy_payment_intent() {
+    use stripe::{ConfirmationMethod, PaymentIntent, PaymentIntentNextActionType, PaymentIntentStatus};
+    let intent: PaymentIntent = json::from_str(
+        r#"{"id": "pi_123", "object": "payment_intent", "allowed_source_types": ["card"], "amount": 1099,
+            "amount_capturable": 0, "amount_received": 0, "application": null, "application_fee_amount": null,
+            "canceled_at": null, "cancellation_reason": null, "capture_method": "automatic",
+            "charges": {"object": "list", "data": [], "has_more": false, "url": "/v1/charges?payment_intent=pi_123"},
+            "client_secret": "pi_123_secret_456", "confirmation_method": "publishable", "created": 1540000000,
+            "currency": "usd", "customer": null, "description": null, "last_payment_error": null,
+            "livemode": false, "metadata": {},
+            "next_source_action": {"type": "authorize_with_url",
+                                   "authorize_with_url": {"return_url": null, "url": "https://hooks.stripe.com"}},
+            "on_behalf_of": null, "receipt_email": null, "review": null, "shipping": null, "source": null,
+            "statement_descriptor": null, "status": "requires_source_action", "transfer_data": null,
+            "transfer_group": null}"#,
+    ).unwrap();
+    assert_eq!(intent.status, PaymentIntentStatus::RequiresSourceAction);
+    assert_eq!(intent.confirmation_method, ConfirmationMethod::Publishable);
+    assert_eq!(intent.payment_method_types, vec!["card".to_string()]);
+    let next_action = intent.next_action.unwrap();
+    assert_eq!(next_action.action_type, PaymentIntentNextActionType::Other("authorize_with_url".to_string()));
+}
+
+#[test]
+fn deserialize_subscription_status() {
+    use stripe::SubscriptionStatus;
+    assert_eq!(json::from_str::<SubscriptionStatus>("\"past_due\"").unwrap(), SubscriptionStatus::PastDue);
+    assert_eq!(
+        json::from_str::<SubscriptionStatus>("\"paused\"").unwrap(),
+        SubscriptionStatus::Other("paused".to_string())
+    );
+}
+
+#[test]
+fn deserialize_review_reason() {
+    use stripe::{ReviewClosedReason, ReviewReason};
+    assert_eq!(json::from_str::<ReviewReason>("\"refunded_as_fraud\"").unwrap(), ReviewReason::RefundedAsFraud);
+    assert_eq!(json::from_str::<ReviewReason>("\"redacted\"").unwrap(), ReviewReason::Other("redacted".to_string()));
+    assert_eq!(
+        json::from_str::<ReviewClosedReason>("\"redacted\"").unwrap(),
+        ReviewClosedReason::Other("redacted".to_string())
+    );
+}
+
+#[test]
+fn deserialize_source_statuses() {
+    use stripe::{SourceCodeVerificationStatus, SourceFlow, SourceStatus};
+    assert_eq!(json::from_str::<SourceFlow>("\"none\"").unwrap(), SourceFlow::None);
+    assert_eq!(json::from_str::<SourceFlow>("\"mandate\"").unwrap(), SourceFlow::Other("mandate".to_string()));
+    assert_eq!(json::from_str::<SourceStatus>("\"expired\"").unwrap(), SourceStatus::Other("expired".to_string()));
+    assert_eq!(
+        json::from_str::<SourceCodeVerificationStatus>("\"pending\"").unwrap(),
+        SourceCodeVerificationStatus::Pending
+    );
+}
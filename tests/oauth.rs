@@ -0,0 +1,51 @@
+extern crate serde_json as json;
+extern crate stripe;
+
+use stripe::{OAuth, OAuthAuthorizeParams, OAuthScope, OAuthStripeUserParams, OAuthToken};
+
+#[test]
+fn authorize_url_includes_params() {
+    let client = stripe::Client::new("sk_test_123");
+    let url = OAuth::authorize_url(
+        &client,
+        OAuthAuthorizeParams {
+            client_id: "ca_123",
+            scope: Some(OAuthScope::ReadWrite),
+            state: Some("csrf token"),
+            stripe_user: Some(OAuthStripeUserParams { email: Some("jenny@example.com"), ..Default::default() }),
+            suggested_capabilities: vec!["card_payments", "transfers"],
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        url,
+        "https://connect.stripe.com/oauth/authorize?response_type=code&client_id=ca_123&scope=read_write\
+         &state=csrf+token&suggested_capabilities%5B%5D=card_payments&suggested_capabilities%5B%5D=transfers\
+         &stripe_user%5Bemail%5D=jenny%40example.com"
+    );
+}
+
+#[test]
+fn authorize_url_uses_connect_url() {
+    let mut client = stripe::Client::new("sk_test_123");
+    client.set_connect_url("http://localhost:12111").unwrap();
+    let url = OAuth::authorize_url(&client, OAuthAuthorizeParams { client_id: "ca_123", ..Default::default() });
+    assert_eq!(url, "http://localhost:12111/oauth/authorize?response_type=code&client_id=ca_123");
+}
+
+#[test]
+fn set_connect_url_rejects_invalid_url() {
+    let mut client = stripe::Client::new("sk_test_123");
+    assert!(client.set_connect_url("localhost:12111/oauth").is_err());
+    assert!(client.set_connect_url("not a url").is_err());
+}
+
+#[test]
+fn deserialize_express_token() {
+    let token: OAuthToken = json::from_str(
+        r#"{"access_token": null, "livemode": false, "refresh_token": null, "scope": "express",
+            "stripe_publishable_key": null, "stripe_user_id": "acct_123", "token_type": null}"#,
+    ).unwrap();
+    assert_eq!(token.scope, OAuthScope::Express);
+    assert_eq!(token.stripe_user_id, "acct_123");
+}
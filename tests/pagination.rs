@@ -0,0 +1,47 @@
+extern crate stripe;
+
+use stripe::{Identifiable, List};
+
+#[derive(Debug)]
+struct Item(String);
+
+impl Identifiable for Item {
+    fn id(&self) -> &str {
+        &self.0
+    }
+}
+
+fn page(ids: &[&str], has_more: bool) -> List<Item> {
+    List {
+        data: ids.iter().map(|id| Item(id.to_string())).collect(),
+        has_more: has_more,
+        total_count: None,
+        url: "/v1/items".to_string(),
+    }
+}
+
+#[test]
+fn paginate_fetches_remaining_pages() {
+    let mut cursors = Vec::new();
+    let items = page(&["a", "b"], true)
+        .paginate(|starting_after| {
+            cursors.push(starting_after.to_string());
+            Ok(match starting_after {
+                "b" => page(&["c", "d"], true),
+                _ => page(&["e"], false),
+            })
+        })
+        .unwrap();
+
+    let ids: Vec<&str> = items.iter().map(|item| item.id()).collect();
+    assert_eq!(ids, vec!["a", "b", "c", "d", "e"]);
+    assert_eq!(cursors, vec!["b", "d"]);
+}
+
+#[test]
+fn paginate_stops_without_more() {
+    let items = page(&["a"], false)
+        .paginate(|_| panic!("should not request another page"))
+        .unwrap();
+    assert_eq!(items.len(), 1);
+}
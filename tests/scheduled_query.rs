@@ -0,0 +1,54 @@
+extern crate serde_json as json;
+extern crate stripe;
+
+use stripe::{ScheduledQueryRun, ScheduledQueryRunStatus};
+
+fn row(fields: &[&str]) -> Vec<String> {
+    fields.iter().map(|field| field.to_string()).collect()
+}
+
+#[test]
+fn parse_csv_splits_rows_and_fields() {
+    let rows = ScheduledQueryRun::parse_csv("id,amount\r\nch_1,100\r\nch_2,\r\n");
+    assert_eq!(rows, vec![row(&["id", "amount"]), row(&["ch_1", "100"]), row(&["ch_2", ""])]);
+}
+
+#[test]
+fn parse_csv_handles_quoted_fields() {
+    let rows = ScheduledQueryRun::parse_csv("id,description\nch_1,\"a, \"\"quoted\"\"\nvalue\"\nch_2,plain");
+    assert_eq!(
+        rows,
+        vec![
+            row(&["id", "description"]),
+            row(&["ch_1", "a, \"quoted\"\nvalue"]),
+            row(&["ch_2", "plain"]),
+        ]
+    );
+}
+
+#[test]
+fn parse_csv_keeps_single_empty_fields() {
+    let rows = ScheduledQueryRun::parse_csv("email\nfoo@example.com\n\nbar@example.com\n");
+    assert_eq!(rows, vec![row(&["email"]), row(&["foo@example.com"]), row(&[""]), row(&["bar@example.com"])]);
+}
+
+#[test]
+fn parse_csv_empty() {
+    assert!(ScheduledQueryRun::parse_csv("").is_empty());
+}
+
+#[test]
+fn download_result_without_file() {
+    let run: ScheduledQueryRun = json::from_str(
+        r#"{"id": "sqr_123", "object": "scheduled_query_run", "created": 1500000000, "data_load_time": 1499990000,
+            "error": null, "file": null, "livemode": false, "result_available_until": 1502592000,
+            "sql": "select count(*) from charges", "status": "timed_out", "title": "Charge count"}"#,
+    ).unwrap();
+    assert_eq!(run.status, ScheduledQueryRunStatus::TimedOut);
+
+    let client = stripe::Client::new("sk_test_123");
+    let mut data = Vec::new();
+    assert_eq!(run.download_result(&client, &mut data).unwrap(), 0);
+    assert!(data.is_empty());
+    assert!(run.rows(&client).unwrap().is_empty());
+}
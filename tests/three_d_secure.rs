@@ -0,0 +1,112 @@
+extern crate serde_json as json;
+extern crate stripe;
+
+use stripe::{Event, Source, ThreeDSecureFlow, ThreeDSecureState};
+
+fn source(id: &str, status: &str, redirect_status: &str) -> Source {
+    json::from_str(&format!(
+        r#"{{
+            "id": "{}",
+            "client_secret": "src_client_secret_123",
+            "created": 1530000000,
+            "flow": "redirect",
+            "livemode": false,
+            "redirect": {{"return_url": "https://example.com/return", "status": "{}", "url": "https://hooks.stripe.com/redirect"}},
+            "status": "{}",
+            "type": "three_d_secure",
+            "three_d_secure": {{"card": "src_card", "authenticated": false}}
+        }}"#,
+        id,
+        redirect_status,
+        status
+    )).unwrap()
+}
+
+fn event(event_type: &str, id: &str, status: &str) -> Event {
+    json::from_str(&format!(
+        r#"{{
+            "type": "{}",
+            "data": {{"object": {{
+                "id": "{}",
+                "object": "source",
+                "client_secret": "src_client_secret_123",
+                "created": 1530000000,
+                "flow": "redirect",
+                "livemode": false,
+                "status": "{}",
+                "type": "three_d_secure",
+                "three_d_secure": {{"card": "src_card"}}
+            }}}}
+        }}"#,
+        event_type,
+        id,
+        status
+    )).unwrap()
+}
+
+#[test]
+fn new_flow_is_created() {
+    let flow = ThreeDSecureFlow::new("src_card");
+    assert_eq!(flow.state(), ThreeDSecureState::Created);
+    assert_eq!(flow.redirect_url(), None);
+    assert_eq!(flow.chargeable_source_id(), None);
+}
+
+#[test]
+fn pending_source_requires_redirect() {
+    let flow = ThreeDSecureFlow::from_source(source("src_3ds", "pending", "pending"));
+    assert_eq!(flow.state(), ThreeDSecureState::PendingRedirect);
+    assert_eq!(flow.card_source_id(), "src_card");
+    assert_eq!(flow.redirect_url(), Some("https://hooks.stripe.com/redirect"));
+}
+
+#[test]
+fn failed_redirect_fails_flow() {
+    let flow = ThreeDSecureFlow::from_source(source("src_3ds", "pending", "failed"));
+    assert_eq!(flow.state(), ThreeDSecureState::Failed);
+    assert_eq!(flow.redirect_url(), None);
+}
+
+#[test]
+fn chargeable_event_advances_flow() {
+    let mut flow = ThreeDSecureFlow::from_source(source("src_3ds", "pending", "pending"));
+    assert_eq!(flow.handle_event(event("source.chargeable", "src_other", "chargeable")), ThreeDSecureState::PendingRedirect);
+    assert_eq!(flow.handle_event(event("source.chargeable", "src_3ds", "chargeable")), ThreeDSecureState::Chargeable);
+    assert_eq!(flow.chargeable_source_id(), Some("src_3ds"));
+}
+
+#[test]
+fn created_flow_adopts_source_for_its_card() {
+    let mut flow = ThreeDSecureFlow::new("src_card");
+    assert_eq!(flow.handle_event(event("source.failed", "src_3ds", "failed")), ThreeDSecureState::Failed);
+
+    let mut flow = ThreeDSecureFlow::new("src_other_card");
+    assert_eq!(flow.handle_event(event("source.chargeable", "src_3ds", "chargeable")), ThreeDSecureState::Created);
+}
+
+#[test]
+fn consumed_source_completes_flow() {
+    let flow = ThreeDSecureFlow::from_source(source("src_3ds", "consumed", "succeeded"));
+    assert_eq!(flow.state(), ThreeDSecureState::Consumed);
+    assert_eq!(flow.chargeable_source_id(), None);
+}
+
+#[test]
+fn completed_redirect_awaits_chargeable() {
+    let flow = ThreeDSecureFlow::from_source(source("src_3ds", "pending", "succeeded"));
+    assert_eq!(flow.state(), ThreeDSecureState::AwaitingChargeable);
+    assert_eq!(flow.redirect_url(), None);
+
+    let flow = ThreeDSecureFlow::from_source(source("src_3ds", "pending", "not_required"));
+    assert_eq!(flow.state(), ThreeDSecureState::AwaitingChargeable);
+    assert_eq!(flow.redirect_url(), None);
+}
+
+#[test]
+fn other_events_are_ignored() {
+    let mut flow = ThreeDSecureFlow::from_source(source("src_3ds", "pending", "pending"));
+    assert_eq!(
+        flow.handle_event(event("customer.source.updated", "src_3ds", "chargeable")),
+        ThreeDSecureState::PendingRedirect
+    );
+}